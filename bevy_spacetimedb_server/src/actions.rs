@@ -0,0 +1,143 @@
+// Standard Library Imports
+use std::marker::PhantomData;
+
+// External Crate Imports
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+
+// Crate Imports
+use crate::BevyWorldAction;
+
+// --- Action Types ---
+
+// Concrete implementation of `BevyWorldAction` for sending a Bevy `Event`.
+struct SendBevyEvent<T: Event + Clone + Send + Sync>(T);
+
+// Spawns a clone of the bundle as a new entity.
+struct SpawnBundle<B: Bundle + Clone>(B);
+
+// Inserts (or replaces) a clone of the resource.
+struct InsertResource<R: Resource + Clone>(R);
+
+// Mutates an existing resource in place.
+struct MutateResource<R: Resource, F: Fn(&mut R) + Send + Sync>(F, PhantomData<R>);
+
+// Runs a registered one-shot system.
+struct RunSystem(SystemId);
+
+// Triggers observers for a clone of the event.
+struct TriggerEvent<E: Event + Clone>(E);
+
+// Runs an arbitrary closure against the world.
+struct WorldClosure<F: Fn(&mut World) + Send + Sync>(F);
+
+// --- Public API: Functions ---
+
+/// Helper function to create a boxed `BevyWorldAction` specifically for sending a Bevy `Event`.
+/// The event type `T` must implement `Event`, `Clone`, `Send`, `Sync`, and be `'static`.
+pub fn create_send_event_action<T: Event + Clone + Send + Sync + 'static>(event: T) -> Box<dyn BevyWorldAction> {
+    log::trace!("Creating SendBevyEvent action for event type: {}", std::any::type_name::<T>());
+    Box::new(SendBevyEvent(event))
+}
+
+/// Creates a boxed `BevyWorldAction` that spawns a new entity with `bundle`.
+pub fn create_spawn_action<B: Bundle + Clone>(bundle: B) -> Box<dyn BevyWorldAction> {
+    log::trace!("Creating SpawnBundle action for bundle type: {}", std::any::type_name::<B>());
+    Box::new(SpawnBundle(bundle))
+}
+
+/// Creates a boxed `BevyWorldAction` that inserts `resource`, replacing any existing value.
+pub fn create_insert_resource_action<R: Resource + Clone>(resource: R) -> Box<dyn BevyWorldAction> {
+    log::trace!("Creating InsertResource action for resource type: {}", std::any::type_name::<R>());
+    Box::new(InsertResource(resource))
+}
+
+/// Creates a boxed `BevyWorldAction` that calls `mutate` on the existing resource `R`.
+/// The action is skipped with a warning if the resource does not exist.
+pub fn create_resource_mut_action<R: Resource>(
+    mutate: impl Fn(&mut R) + Send + Sync + 'static,
+) -> Box<dyn BevyWorldAction> {
+    log::trace!("Creating MutateResource action for resource type: {}", std::any::type_name::<R>());
+    Box::new(MutateResource(mutate, PhantomData))
+}
+
+/// Creates a boxed `BevyWorldAction` that runs the one-shot system registered as `system`
+/// (see `World::register_system`).
+pub fn create_run_system_action(system: SystemId) -> Box<dyn BevyWorldAction> {
+    log::trace!("Creating RunSystem action for system {:?}", system);
+    Box::new(RunSystem(system))
+}
+
+/// Creates a boxed `BevyWorldAction` that triggers observers with `event` (`World::trigger`).
+pub fn create_trigger_action<E: Event + Clone>(event: E) -> Box<dyn BevyWorldAction> {
+    log::trace!("Creating TriggerEvent action for event type: {}", std::any::type_name::<E>());
+    Box::new(TriggerEvent(event))
+}
+
+/// Creates a boxed `BevyWorldAction` from a closure with full access to the `World`.
+pub fn create_world_action(f: impl Fn(&mut World) + Send + Sync + 'static) -> Box<dyn BevyWorldAction> {
+    log::trace!("Creating WorldClosure action.");
+    Box::new(WorldClosure(f))
+}
+
+// --- Trait Implementations ---
+
+impl<T: Event + Clone + Send + Sync> BevyWorldAction for SendBevyEvent<T> {
+    fn execute(&self, world: &mut World) {
+        // Clone the event data to send it into the Bevy event system.
+        world.send_event(self.0.clone());
+        log::trace!("Executed SendBevyEvent action (sent event).");
+    }
+}
+
+impl<B: Bundle + Clone> BevyWorldAction for SpawnBundle<B> {
+    fn execute(&self, world: &mut World) {
+        let entity = world.spawn(self.0.clone()).id();
+        log::trace!("Executed SpawnBundle action (spawned {}).", entity);
+    }
+}
+
+impl<R: Resource + Clone> BevyWorldAction for InsertResource<R> {
+    fn execute(&self, world: &mut World) {
+        world.insert_resource(self.0.clone());
+        log::trace!("Executed InsertResource action.");
+    }
+}
+
+impl<R: Resource, F: Fn(&mut R) + Send + Sync> BevyWorldAction for MutateResource<R, F> {
+    fn execute(&self, world: &mut World) {
+        match world.get_resource_mut::<R>() {
+            Some(mut resource) => {
+                (self.0)(&mut *resource);
+                log::trace!("Executed MutateResource action.");
+            }
+            None => log::warn!(
+                "MutateResource action skipped: resource {} does not exist.",
+                std::any::type_name::<R>()
+            ),
+        }
+    }
+}
+
+impl BevyWorldAction for RunSystem {
+    fn execute(&self, world: &mut World) {
+        match world.run_system(self.0) {
+            Ok(()) => log::trace!("Executed RunSystem action."),
+            Err(e) => log::error!("RunSystem action failed for system {:?}: {}", self.0, e),
+        }
+    }
+}
+
+impl<E: Event + Clone> BevyWorldAction for TriggerEvent<E> {
+    fn execute(&self, world: &mut World) {
+        world.trigger(self.0.clone());
+        log::trace!("Executed TriggerEvent action.");
+    }
+}
+
+impl<F: Fn(&mut World) + Send + Sync> BevyWorldAction for WorldClosure<F> {
+    fn execute(&self, world: &mut World) {
+        (self.0)(world);
+        log::trace!("Executed WorldClosure action.");
+    }
+}
@@ -0,0 +1,44 @@
+// External Crate Imports
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use spacetimedb::ReducerContext;
+
+// Crate Imports
+use crate::{rehydrate, schedules, RoomId};
+
+// --- Public API: Traits ---
+
+/// Extension trait for registering SpacetimeDB integration hooks on a Bevy `App`.
+pub trait StdbAppExt {
+    /// Registers a loader that rebuilds Bevy entities from the existing rows of `table`.
+    ///
+    /// `load` returns the primary key of each row belonging to the given room,
+    /// together with the bundle to spawn for it.
+    /// Spawned entities get `SpacetimeId(key)` and are linked in the `StdbEntityMap`,
+    /// which also raises the row ID counter above every loaded key.
+    fn add_row_loader<B: Bundle>(
+        &mut self,
+        table: &'static str,
+        load: fn(&ReducerContext, RoomId) -> Vec<(u32, B)>,
+    ) -> &mut Self;
+
+    /// Runs the schedule `label` from `run_bevy_update` once every `every_n_ticks` ticks,
+    /// after the schedules registered before it. `Main` is registered by default.
+    fn add_tick_schedule(&mut self, label: impl ScheduleLabel, every_n_ticks: u32) -> &mut Self;
+}
+
+impl StdbAppExt for App {
+    fn add_row_loader<B: Bundle>(
+        &mut self,
+        table: &'static str,
+        load: fn(&ReducerContext, RoomId) -> Vec<(u32, B)>,
+    ) -> &mut Self {
+        rehydrate::register_row_loader(self, table, load);
+        self
+    }
+
+    fn add_tick_schedule(&mut self, label: impl ScheduleLabel, every_n_ticks: u32) -> &mut Self {
+        schedules::register_tick_schedule(self, label.intern(), every_n_ticks);
+        self
+    }
+}
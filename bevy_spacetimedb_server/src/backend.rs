@@ -0,0 +1,229 @@
+// Standard Library Imports
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+
+// External Crate Imports
+use spacetimedb::ReducerContext;
+
+// Crate Imports
+use crate::StdbReadRow;
+
+// --- Public API: Traits ---
+
+/// A row of a SpacetimeDB table with a `u32` primary key, writable through a `DbBackend`.
+///
+/// The associated functions perform the operation on the real tables of a `ReducerContext`;
+/// `InMemoryDb` stores rows without them. Rows are looked up through `StdbReadRow::find`.
+pub trait StdbRow: StdbReadRow<Key = u32> + Clone + Send + Sync {
+    /// Name of the SpacetimeDB table.
+    const TABLE: &'static str;
+
+    /// Primary key of the row.
+    fn key(&self) -> u32;
+
+    /// Inserts `row`, failing if its primary key (or another unique column) is taken.
+    fn insert(ctx: &ReducerContext, row: Self) -> Result<(), String>;
+
+    /// Replaces the row with the same primary key. Returns `false` if there is none.
+    fn update(ctx: &ReducerContext, row: Self) -> bool;
+
+    /// Deletes the row with primary key `key`. Returns `false` if there is none.
+    fn delete(ctx: &ReducerContext, key: u32) -> bool;
+}
+
+/// Storage that `DbCommand`s are applied to.
+///
+/// `ReducerBackend` writes to the SpacetimeDB tables of a reducer call, and `InMemoryDb`
+/// keeps rows in memory so Bevy server systems can be tested without the host.
+/// Use the typed methods on `dyn DbBackend` (`insert`, `update`, `delete`, `find`);
+/// the `*_any` methods are their type-erased building blocks.
+pub trait DbBackend {
+    /// Inserts `row` with primary key `key` into `table`.
+    fn insert_any(&mut self, table: &'static RowTable, key: u32, row: AnyRow) -> Result<(), String>;
+
+    /// Replaces the row with primary key `key` of `table`. Returns `false` if there is none.
+    fn update_any(&mut self, table: &'static RowTable, key: u32, row: AnyRow) -> Result<bool, String>;
+
+    /// Deletes the row with primary key `key` of `table`. Returns `false` if there is none.
+    fn delete_any(&mut self, table: &'static RowTable, key: u32) -> Result<bool, String>;
+
+    /// Returns a copy of the row with primary key `key` of `table`.
+    fn find_any(&self, table: &'static RowTable, key: u32) -> Option<AnyRow>;
+
+    /// The reducer context behind the backend, if any, for work the typed API can not express.
+    fn reducer_context(&self) -> Option<&ReducerContext> {
+        None
+    }
+}
+
+// --- Public API: Types ---
+
+/// A type-erased `StdbRow`.
+pub type AnyRow = Box<dyn Any + Send + Sync>;
+
+/// Type-erased operations of a `StdbRow` type, obtained with `RowTable::of`.
+pub struct RowTable {
+    /// Name of the SpacetimeDB table.
+    pub name: &'static str,
+    insert: fn(&ReducerContext, AnyRow) -> Result<(), String>,
+    update: fn(&ReducerContext, AnyRow) -> bool,
+    delete: fn(&ReducerContext, u32) -> bool,
+    find: fn(&ReducerContext, u32) -> Option<AnyRow>,
+    clone: fn(&AnyRow) -> AnyRow,
+}
+
+impl RowTable {
+    /// Returns the operations of the row type `R`.
+    pub fn of<R: StdbRow>() -> &'static RowTable {
+        &RowTableOf::<R>::TABLE
+    }
+}
+
+// Holder of the `RowTable` constant of each row type.
+struct RowTableOf<R>(PhantomData<R>);
+
+impl<R: StdbRow> RowTableOf<R> {
+    const TABLE: RowTable = RowTable {
+        name: R::TABLE,
+        insert: |ctx, row| R::insert(ctx, downcast_row(row)),
+        update: |ctx, row| R::update(ctx, downcast_row(row)),
+        delete: |ctx, key| R::delete(ctx, key),
+        find: |ctx, key| R::find(ctx, &key).map(|row| Box::new(row) as AnyRow),
+        clone: |row| Box::new(row.downcast_ref::<R>().expect("row type matches its RowTable").clone()),
+    };
+}
+
+// Unboxes a row passed through a `RowTable` of the same type.
+fn downcast_row<R: StdbRow>(row: AnyRow) -> R {
+    *row.downcast::<R>().expect("row type matches its RowTable")
+}
+
+impl dyn DbBackend + '_ {
+    /// Inserts `row` into its table.
+    pub fn insert<R: StdbRow>(&mut self, row: R) -> Result<(), String> {
+        self.insert_any(RowTable::of::<R>(), row.key(), Box::new(row))
+    }
+
+    /// Replaces the row with the primary key of `row`. Returns `false` if there is none.
+    pub fn update<R: StdbRow>(&mut self, row: R) -> Result<bool, String> {
+        self.update_any(RowTable::of::<R>(), row.key(), Box::new(row))
+    }
+
+    /// Deletes the `R` row with primary key `key`. Returns `false` if there is none.
+    pub fn delete<R: StdbRow>(&mut self, key: u32) -> Result<bool, String> {
+        self.delete_any(RowTable::of::<R>(), key)
+    }
+
+    /// Returns the `R` row with primary key `key`.
+    pub fn find<R: StdbRow>(&self, key: u32) -> Option<R> {
+        self.find_any(RowTable::of::<R>(), key).map(downcast_row)
+    }
+}
+
+/// `DbBackend` writing to the SpacetimeDB tables of a reducer call.
+/// Used by `process_bevy_commands`.
+pub struct ReducerBackend<'a> {
+    ctx: &'a ReducerContext,
+}
+
+impl<'a> ReducerBackend<'a> {
+    /// Creates a backend for the reducer running in `ctx`.
+    pub fn new(ctx: &'a ReducerContext) -> Self {
+        Self { ctx }
+    }
+}
+
+impl DbBackend for ReducerBackend<'_> {
+    fn insert_any(&mut self, table: &'static RowTable, _key: u32, row: AnyRow) -> Result<(), String> {
+        (table.insert)(self.ctx, row)
+    }
+
+    fn update_any(&mut self, table: &'static RowTable, _key: u32, row: AnyRow) -> Result<bool, String> {
+        Ok((table.update)(self.ctx, row))
+    }
+
+    fn delete_any(&mut self, table: &'static RowTable, key: u32) -> Result<bool, String> {
+        Ok((table.delete)(self.ctx, key))
+    }
+
+    fn find_any(&self, table: &'static RowTable, key: u32) -> Option<AnyRow> {
+        (table.find)(self.ctx, key)
+    }
+
+    fn reducer_context(&self) -> Option<&ReducerContext> {
+        Some(self.ctx)
+    }
+}
+
+/// In-memory `DbBackend` for unit tests, storing rows by table name and primary key.
+///
+/// Only primary keys are enforced; other unique constraints of the real tables are not.
+/// Commands that need `reducer_context` fail against it.
+#[derive(Default)]
+pub struct InMemoryDb {
+    tables: HashMap<&'static str, BTreeMap<u32, AnyRow>>,
+}
+
+impl InMemoryDb {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `row` to the store, replacing any row with the same primary key.
+    pub fn with_row<R: StdbRow>(mut self, row: R) -> Self {
+        self.tables.entry(R::TABLE).or_default().insert(row.key(), Box::new(row));
+        self
+    }
+
+    /// Returns the `R` row with primary key `key`.
+    pub fn row<R: StdbRow>(&self, key: u32) -> Option<&R> {
+        self.tables.get(R::TABLE)?.get(&key)?.downcast_ref::<R>()
+    }
+
+    /// Returns all `R` rows, ordered by primary key.
+    pub fn rows<R: StdbRow>(&self) -> Vec<&R> {
+        self.tables
+            .get(R::TABLE)
+            .map(|rows| rows.values().filter_map(|row| row.downcast_ref::<R>()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Number of rows in `table`.
+    pub fn len(&self, table: &str) -> usize {
+        self.tables.get(table).map_or(0, BTreeMap::len)
+    }
+}
+
+impl DbBackend for InMemoryDb {
+    fn insert_any(&mut self, table: &'static RowTable, key: u32, row: AnyRow) -> Result<(), String> {
+        let rows = self.tables.entry(table.name).or_default();
+        if rows.contains_key(&key) {
+            return Err(format!("Duplicate primary key {} in table '{}'", key, table.name));
+        }
+        rows.insert(key, row);
+        Ok(())
+    }
+
+    fn update_any(&mut self, table: &'static RowTable, key: u32, row: AnyRow) -> Result<bool, String> {
+        match self.tables.get_mut(table.name).and_then(|rows| rows.get_mut(&key)) {
+            Some(existing) => {
+                *existing = row;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn delete_any(&mut self, table: &'static RowTable, key: u32) -> Result<bool, String> {
+        Ok(self
+            .tables
+            .get_mut(table.name)
+            .is_some_and(|rows| rows.remove(&key).is_some()))
+    }
+
+    fn find_any(&self, table: &'static RowTable, key: u32) -> Option<AnyRow> {
+        self.tables.get(table.name)?.get(&key).map(table.clone)
+    }
+}
@@ -0,0 +1,146 @@
+// Standard Library Imports
+use std::collections::HashMap;
+
+// Crate Imports
+use crate::{DbCommand, QueuedDbCommand};
+
+// Per-row bookkeeping while coalescing: slots of the still-pending insert and
+// of the latest update/upsert queued for the same (table, key).
+#[derive(Default)]
+struct RowSlots {
+    insert: Option<usize>,
+    write: Option<usize>,
+}
+
+/// Merges the commands queued during one tick, preserving their relative order.
+///
+/// Per `(table, key)`:
+/// - only the last `Update`/`Upsert` is kept (last write wins);
+/// - an `Insert` followed by a `Delete` cancels out, together with any writes in between;
+/// - a `Delete` drops the writes queued before it.
+///
+/// `ExecuteClosure` commands are opaque and act as barriers: commands on either side
+/// of a closure are never merged with each other.
+pub(crate) fn coalesce_commands(commands: Vec<QueuedDbCommand>) -> Vec<QueuedDbCommand> {
+    let mut slots: Vec<Option<QueuedDbCommand>> = Vec::with_capacity(commands.len());
+    let mut rows: HashMap<(&'static str, u32), RowSlots> = HashMap::new();
+
+    for command in commands {
+        let Some(target) = command.command.target() else {
+            // Closures may touch any row; don't merge across them.
+            rows.clear();
+            slots.push(Some(command));
+            continue;
+        };
+
+        let row = rows.entry(target).or_default();
+        match command.command {
+            DbCommand::Insert(_) => {
+                row.insert = Some(slots.len());
+                slots.push(Some(command));
+            }
+            DbCommand::Update(_) | DbCommand::Upsert(_) => {
+                if let Some(previous) = row.write.replace(slots.len()) {
+                    // Writes carry the full row, so the later one supersedes the earlier.
+                    if let Some(dropped) = slots[previous].take() {
+                        if dropped.source != command.source {
+                            log::debug!(
+                                "Write to '{}' row {} queued by '{}' superseded by '{}'.",
+                                target.0, target.1, dropped.source, command.source
+                            );
+                        }
+                    }
+                }
+                slots.push(Some(command));
+            }
+            DbCommand::Delete(_) => {
+                if let Some(previous) = row.write.take() {
+                    slots[previous] = None;
+                }
+                match row.insert.take() {
+                    // Inserted and deleted within the same tick: nothing reaches the table.
+                    Some(inserted) => slots[inserted] = None,
+                    None => slots.push(Some(command)),
+                }
+            }
+            DbCommand::ExecuteClosure(_) => unreachable!("closures have no target"),
+        }
+    }
+
+    slots.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DbCommandKind::{self, Closure, Delete, Insert, Update, Upsert};
+
+    fn queued(command: DbCommand) -> QueuedDbCommand {
+        QueuedDbCommand { command, source: "test".into(), entity: None }
+    }
+
+    fn insert(key: u32) -> QueuedDbCommand {
+        queued(DbCommand::insert("t", key, |_| Ok(None)))
+    }
+
+    fn update(key: u32) -> QueuedDbCommand {
+        queued(DbCommand::update("t", key, |_| Ok(None)))
+    }
+
+    fn upsert(key: u32) -> QueuedDbCommand {
+        queued(DbCommand::upsert("t", key, |_| Ok(None)))
+    }
+
+    fn delete(key: u32) -> QueuedDbCommand {
+        queued(DbCommand::delete("t", key, |_| Ok(None)))
+    }
+
+    fn closure() -> QueuedDbCommand {
+        queued(DbCommand::ExecuteClosure(Box::new(|_| Ok(None))))
+    }
+
+    // Coalesces `commands` and returns the kind and key of the remaining ones, in order.
+    fn coalesced(commands: Vec<QueuedDbCommand>) -> Vec<(DbCommandKind, Option<u32>)> {
+        coalesce_commands(commands)
+            .iter()
+            .map(|queued| (queued.command.kind(), queued.command.target().map(|(_, key)| key)))
+            .collect()
+    }
+
+    #[test]
+    fn last_write_per_row_wins() {
+        // The surviving write is the one queued after the insert of row 2.
+        let kept = coalesced(vec![update(1), insert(2), upsert(1)]);
+        assert_eq!(kept, vec![(Insert, Some(2)), (Upsert, Some(1))]);
+    }
+
+    #[test]
+    fn writes_to_different_rows_are_kept() {
+        let kept = coalesced(vec![update(1), update(2)]);
+        assert_eq!(kept, vec![(Update, Some(1)), (Update, Some(2))]);
+    }
+
+    #[test]
+    fn insert_then_delete_cancels_out() {
+        let kept = coalesced(vec![insert(1), update(1), delete(1), update(2)]);
+        assert_eq!(kept, vec![(Update, Some(2))]);
+    }
+
+    #[test]
+    fn delete_drops_earlier_writes() {
+        let kept = coalesced(vec![update(1), delete(1)]);
+        assert_eq!(kept, vec![(Delete, Some(1))]);
+    }
+
+    #[test]
+    fn insert_after_delete_is_kept() {
+        let kept = coalesced(vec![delete(1), insert(1)]);
+        assert_eq!(kept, vec![(Delete, Some(1)), (Insert, Some(1))]);
+    }
+
+    #[test]
+    fn closures_are_barriers() {
+        let kept = coalesced(vec![update(1), closure(), update(1)]);
+        assert_eq!(kept, vec![(Update, Some(1)), (Closure, None), (Update, Some(1))]);
+    }
+}
@@ -0,0 +1,99 @@
+// External Crate Imports
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use spacetimedb::{Identity, ReducerContext, Timestamp};
+
+// --- Internal State ---
+
+// Bevy `Resource` pointing at the `ReducerContext` of the reducer currently running the world.
+// Only present while `with_reducer_scope` runs, so the pointer never outlives the context.
+#[derive(Resource)]
+struct ReducerScope(*const ReducerContext);
+
+// SAFETY: SpacetimeDB runs reducers one at a time on a single thread, and the scope is
+// removed before the reducer returns, so the context is never accessed from another thread.
+unsafe impl Send for ReducerScope {}
+unsafe impl Sync for ReducerScope {}
+
+// --- Public API: Traits ---
+
+/// A SpacetimeDB table row that Bevy systems can read through `StdbDb`.
+///
+/// Only reads are described, so it can be implemented for any table whatever its
+/// primary key, e.g. a singleton config row or a table keyed by `Identity`.
+pub trait StdbReadRow: Sized + 'static {
+    /// Type of the table's primary key.
+    type Key;
+
+    /// Returns the row with primary key `key`.
+    fn find(ctx: &ReducerContext, key: &Self::Key) -> Option<Self>;
+
+    /// Returns every row of the table.
+    fn all(ctx: &ReducerContext) -> Vec<Self>;
+}
+
+// --- Public API: Types ---
+
+/// Bevy `SystemParam` giving systems read access to the SpacetimeDB tables while
+/// the world runs inside a reducer, i.e. during `run_bevy_update` and `run_bevy_now`.
+///
+/// Rows are read through their `StdbReadRow` implementation; the table handles themselves
+/// are not exposed, so writes must go through `StdbCommands` and are coalesced and reported
+/// like any other `DbCommand`. Outside a reducer (e.g. when an `App` is updated directly)
+/// every accessor fails with an error.
+#[derive(SystemParam)]
+pub struct StdbDb<'w> {
+    scope: Option<Res<'w, ReducerScope>>,
+}
+
+impl StdbDb<'_> {
+    /// Returns `true` if the system runs inside a reducer, so table reads are available.
+    pub fn is_available(&self) -> bool {
+        self.scope.is_some()
+    }
+
+    /// Returns the `R` row with primary key `key`.
+    pub fn find<R: StdbReadRow>(&self, key: &R::Key) -> Result<Option<R>, String> {
+        self.ctx().map(|ctx| R::find(ctx, key))
+    }
+
+    /// Returns every row of `R`'s table.
+    pub fn all<R: StdbReadRow>(&self) -> Result<Vec<R>, String> {
+        self.ctx().map(R::all)
+    }
+
+    /// `Identity` of the caller of the running reducer.
+    pub fn sender(&self) -> Result<Identity, String> {
+        self.ctx().map(|ctx| ctx.sender)
+    }
+
+    /// Timestamp of the running reducer.
+    pub fn timestamp(&self) -> Result<Timestamp, String> {
+        self.ctx().map(|ctx| ctx.timestamp)
+    }
+
+    fn ctx(&self) -> Result<&ReducerContext, String> {
+        let scope = self.scope.as_ref().ok_or_else(|| {
+            "StdbDb is only available while the Bevy world runs inside a SpacetimeDB reducer \
+             (run_bevy_update or run_bevy_now)"
+                .to_string()
+        })?;
+        // SAFETY: `ReducerScope` only exists while `with_reducer_scope` borrows the context,
+        // and the returned reference can not outlive the system run that holds `self`.
+        Ok(unsafe { &*scope.0 })
+    }
+}
+
+// --- Internal Functions ---
+
+/// Runs `f` with `ctx` exposed to Bevy systems through `StdbDb`.
+pub(crate) fn with_reducer_scope<R>(
+    world: &mut World,
+    ctx: &ReducerContext,
+    f: impl FnOnce(&mut World) -> R,
+) -> R {
+    world.insert_resource(ReducerScope(ctx as *const ReducerContext));
+    let result = f(world);
+    world.remove_resource::<ReducerScope>();
+    result
+}
@@ -0,0 +1,208 @@
+// Standard Library Imports
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// External Crate Imports
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+
+// Crate Imports
+use crate::{DbCommandFailed, SpacetimeId};
+
+// --- Internal State ---
+
+// Next row ID to allocate, shared by the maps of all rooms so IDs are unique module-wide.
+// Starts at 1 to match typical database auto-increment starting points.
+// Lives in module memory; seeded from the database with `reserve_row_ids` after a restart.
+static NEXT_ROW_ID: AtomicU32 = AtomicU32::new(1);
+
+// --- Public API: Types ---
+
+/// Bevy `Resource` mapping Bevy entities to SpacetimeDB row IDs and back.
+///
+/// Row IDs are allocated from a monotonically increasing counter starting at 1, so a
+/// recycled Bevy entity index can not take over an old row. The counter is shared by all
+/// rooms, so IDs are also unique across worlds.
+///
+/// The counter starts at 1 again when the module restarts; restore it with `reserve_row_ids`
+/// (from a persisted `next_row_id`, or above every existing row) before any room allocates.
+/// Lookups are keyed by the full `Entity` (index and generation) and are O(1) in both directions.
+#[derive(Resource, Debug, Default)]
+pub struct StdbEntityMap {
+    ids: HashMap<Entity, u32>,
+    entities: HashMap<u32, Entity>,
+}
+
+impl StdbEntityMap {
+    /// Returns the row ID of `entity`, allocating a fresh one if it has none yet.
+    pub fn allocate(&mut self, entity: Entity) -> u32 {
+        if let Some(&id) = self.ids.get(&entity) {
+            return id;
+        }
+        let id = NEXT_ROW_ID.fetch_add(1, Ordering::Relaxed);
+        self.ids.insert(entity, id);
+        self.entities.insert(id, entity);
+        log::trace!("Allocated SpacetimeDB row ID {} for Bevy entity {}.", id, entity);
+        id
+    }
+
+    /// Links `entity` to an existing row `id`, e.g. one reported by the database.
+    /// Any previous link of either side is replaced, and future allocations start above `id`.
+    pub fn insert(&mut self, entity: Entity, id: u32) {
+        if let Some(old_id) = self.ids.insert(entity, id) {
+            if old_id != id {
+                self.entities.remove(&old_id);
+            }
+        }
+        if let Some(old_entity) = self.entities.insert(id, entity) {
+            if old_entity != entity {
+                self.ids.remove(&old_entity);
+            }
+        }
+        NEXT_ROW_ID.fetch_max(id.saturating_add(1), Ordering::Relaxed);
+    }
+
+    /// Returns the row ID linked to `entity`.
+    pub fn id_of(&self, entity: Entity) -> Option<u32> {
+        self.ids.get(&entity).copied()
+    }
+
+    /// Returns the Bevy entity linked to row `id`.
+    pub fn entity_of(&self, id: u32) -> Option<Entity> {
+        self.entities.get(&id).copied()
+    }
+
+    /// Unlinks `entity`, returning its row ID. The ID is not reused.
+    pub fn remove_entity(&mut self, entity: Entity) -> Option<u32> {
+        let id = self.ids.remove(&entity)?;
+        self.entities.remove(&id);
+        Some(id)
+    }
+
+    /// Unlinks row `id`, returning its Bevy entity. The ID is not reused.
+    pub fn remove_id(&mut self, id: u32) -> Option<Entity> {
+        let entity = self.entities.remove(&id)?;
+        self.ids.remove(&entity);
+        Some(entity)
+    }
+
+    /// Number of linked entities.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if no entities are linked.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+// --- Public API: Functions ---
+
+/// Returns the row ID the next allocation of any `StdbEntityMap` will return.
+/// Persist it in the reducer that writes the allocated rows, to restore it after a restart.
+pub fn next_row_id() -> u32 {
+    NEXT_ROW_ID.load(Ordering::Relaxed)
+}
+
+/// Raises the row ID counter shared by all `StdbEntityMap`s, so future allocations
+/// return `next` or above. Lower values are ignored.
+///
+/// Call it with a persisted `next_row_id` (or an ID above every existing row) before
+/// allocating after a module restart, since rooms are rebuilt lazily and a room's
+/// rehydrated rows only cover that room.
+pub fn reserve_row_ids(next: u32) {
+    let previous = NEXT_ROW_ID.fetch_max(next, Ordering::Relaxed);
+    if next > previous {
+        log::debug!("Row ID counter raised from {} to {}.", previous, next);
+    }
+}
+
+// --- Bevy Systems ---
+
+/// System run during `Last` that unlinks despawned entities from the `StdbEntityMap`.
+/// It runs after `PostUpdate`, so mirroring can still resolve the row ID of a despawned entity.
+///
+/// Entities whose insert did not take effect never received a `SpacetimeId`; they are
+/// unlinked once despawned, as reported by `DbCommandFailed`.
+pub fn unmap_despawned_entities_system(
+    mut removed: RemovedComponents<SpacetimeId>,
+    mut failures: EventReader<DbCommandFailed>,
+    entities: &Entities,
+    mut entity_map: ResMut<StdbEntityMap>,
+) {
+    let failed = failures.read().filter_map(|failure| failure.entity);
+    for bevy_entity in removed.read().chain(failed) {
+        if entities.contains(bevy_entity) {
+            continue; // The entity is still alive, e.g. only the component was removed.
+        }
+        if let Some(id) = entity_map.remove_entity(bevy_entity) {
+            log::trace!("Unmapped despawned Bevy entity {} (row ID {}).", bevy_entity, id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The row ID counter is shared by all tests, so they only assert relative IDs.
+
+    #[test]
+    fn allocate_is_stable_per_entity() {
+        let mut world = World::new();
+        let (a, b) = (world.spawn_empty().id(), world.spawn_empty().id());
+        let mut map = StdbEntityMap::default();
+
+        let id_a = map.allocate(a);
+        let id_b = map.allocate(b);
+        assert_ne!(id_a, id_b);
+        assert_eq!(map.allocate(a), id_a);
+        assert_eq!(map.id_of(b), Some(id_b));
+        assert_eq!(map.entity_of(id_a), Some(a));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn removed_ids_are_not_reused() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let mut map = StdbEntityMap::default();
+
+        let id = map.allocate(entity);
+        assert_eq!(map.remove_entity(entity), Some(id));
+        assert!(map.is_empty());
+        assert_eq!(map.entity_of(id), None);
+        assert!(map.allocate(entity) > id);
+    }
+
+    #[test]
+    fn insert_replaces_links_and_raises_the_counter() {
+        let mut world = World::new();
+        let (a, b) = (world.spawn_empty().id(), world.spawn_empty().id());
+        let mut map = StdbEntityMap::default();
+        let id = next_row_id().saturating_add(1000);
+
+        map.insert(a, id);
+        assert!(next_row_id() > id);
+        assert_eq!(map.entity_of(id), Some(a));
+
+        // Linking the row to another entity unlinks the first one.
+        map.insert(b, id);
+        assert_eq!(map.id_of(a), None);
+        assert_eq!(map.remove_id(id), Some(b));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn reserve_row_ids_only_raises_the_counter() {
+        let next = next_row_id().saturating_add(1000);
+        reserve_row_ids(next);
+        reserve_row_ids(1);
+        assert!(next_row_id() >= next);
+
+        let mut world = World::new();
+        let mut map = StdbEntityMap::default();
+        assert!(map.allocate(world.spawn_empty().id()) >= next);
+    }
+}
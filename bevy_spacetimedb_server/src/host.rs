@@ -0,0 +1,164 @@
+// Standard Library Imports
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+
+// External Crate Imports
+use bevy::prelude::*;
+use once_cell::sync::Lazy;
+use spin::{Mutex, MutexGuard};
+
+// --- Internal State ---
+
+// Slots holding the Bevy `App` of each room installed by the module.
+// The mutex is never waited on; `try_lock` is used to detect re-entrant borrows.
+struct AppSlots(Mutex<HashMap<RoomId, App>>);
+
+// SAFETY: SpacetimeDB runs reducers one at a time on a single thread, so the apps
+// (which are not `Send`) are never actually accessed from two threads. The mutex
+// rejects overlapping borrows, e.g. a reducer borrowing an app from inside a Bevy system.
+unsafe impl Send for AppSlots {}
+unsafe impl Sync for AppSlots {}
+
+static BEVY_APPS: Lazy<AppSlots> = Lazy::new(|| AppSlots(Mutex::new(HashMap::new())));
+
+// --- Public API: Types ---
+
+/// Identifier of a room: an independent Bevy world (e.g. a match instance) hosted by the module.
+pub type RoomId = u32;
+
+/// The room used by the single-world API (`install_app`, `borrow_app`, ...).
+pub const DEFAULT_ROOM: RoomId = 0;
+
+/// Bevy `Resource` holding the ID of the room an `App` belongs to.
+/// Inserted when the app is installed.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentRoom(pub RoomId);
+
+/// Checked mutable borrow of an installed Bevy `App`, returned by `borrow_room_app`.
+/// The app is released when the guard is dropped. While it is held, no other room's
+/// app can be borrowed either.
+pub struct AppGuard {
+    slots: MutexGuard<'static, HashMap<RoomId, App>>,
+    room: RoomId,
+}
+
+impl AppGuard {
+    /// The room of the borrowed app.
+    pub fn room(&self) -> RoomId {
+        self.room
+    }
+}
+
+impl Deref for AppGuard {
+    type Target = App;
+
+    fn deref(&self) -> &App {
+        self.slots.get(&self.room).expect("AppGuard is only created for an installed App")
+    }
+}
+
+impl DerefMut for AppGuard {
+    fn deref_mut(&mut self) -> &mut App {
+        self.slots.get_mut(&self.room).expect("AppGuard is only created for an installed App")
+    }
+}
+
+// --- Public API: Functions ---
+
+/// Installs the Bevy `App` of `room`, inserting its `CurrentRoom` resource.
+/// Fails if the room already has an app.
+pub fn install_room_app(room: RoomId, mut app: App) -> Result<(), String> {
+    let mut slots = BEVY_APPS
+        .0
+        .try_lock()
+        .ok_or_else(|| "Bevy App is currently borrowed; cannot install".to_string())?;
+    if slots.contains_key(&room) {
+        return Err(format!("Bevy App for room {} is already installed", room));
+    }
+    app.insert_resource(CurrentRoom(room));
+    slots.insert(room, app);
+    log::info!("Bevy App installed for room {}.", room);
+    Ok(())
+}
+
+/// Removes and returns the Bevy `App` of `room`, e.g. when a match ends.
+pub fn uninstall_room_app(room: RoomId) -> Result<App, String> {
+    let mut slots = BEVY_APPS
+        .0
+        .try_lock()
+        .ok_or_else(|| "Bevy App is currently borrowed; cannot uninstall".to_string())?;
+    let app = slots
+        .remove(&room)
+        .ok_or_else(|| format!("Bevy App for room {} is not initialized", room))?;
+    log::info!("Bevy App uninstalled for room {}.", room);
+    Ok(app)
+}
+
+/// Returns `true` if `room` has an installed Bevy `App`.
+/// Fails while an app is borrowed, since the installed rooms can't be inspected then.
+pub fn is_room_app_installed(room: RoomId) -> Result<bool, String> {
+    let slots = BEVY_APPS
+        .0
+        .try_lock()
+        .ok_or_else(|| "Bevy App is already borrowed (re-entrant access)".to_string())?;
+    Ok(slots.contains_key(&room))
+}
+
+/// Returns the rooms with an installed Bevy `App`, in ascending order.
+/// Fails while an app is borrowed.
+pub fn installed_rooms() -> Result<Vec<RoomId>, String> {
+    let slots = BEVY_APPS
+        .0
+        .try_lock()
+        .ok_or_else(|| "Bevy App is already borrowed (re-entrant access)".to_string())?;
+    let mut rooms: Vec<RoomId> = slots.keys().copied().collect();
+    rooms.sort_unstable();
+    Ok(rooms)
+}
+
+/// Mutably borrows the installed Bevy `App` of `room`.
+///
+/// Fails if the room has no app, or if an app is already borrowed (re-entrant access).
+pub fn borrow_room_app(room: RoomId) -> Result<AppGuard, String> {
+    let slots = BEVY_APPS
+        .0
+        .try_lock()
+        .ok_or_else(|| "Bevy App is already borrowed (re-entrant access)".to_string())?;
+    if !slots.contains_key(&room) {
+        return Err(format!("Bevy App for room {} is not initialized", room));
+    }
+    Ok(AppGuard { slots, room })
+}
+
+/// Runs `f` with a mutable borrow of the installed Bevy `App` of `room`.
+/// Fails like `borrow_room_app` without calling `f`.
+pub fn with_room_app<R>(room: RoomId, f: impl FnOnce(&mut App) -> R) -> Result<R, String> {
+    let mut app = borrow_room_app(room)?;
+    Ok(f(&mut app))
+}
+
+/// Installs the Bevy `App` driven by the module's reducers in the `DEFAULT_ROOM`.
+/// This should typically be called once from the SpacetimeDB `init` reducer.
+/// Fails if an app is already installed.
+pub fn install_app(app: App) -> Result<(), String> {
+    install_room_app(DEFAULT_ROOM, app)
+}
+
+/// Returns `true` if a Bevy `App` has been installed in the `DEFAULT_ROOM`.
+/// Fails while an app is borrowed, like `is_room_app_installed`.
+pub fn is_app_installed() -> Result<bool, String> {
+    is_room_app_installed(DEFAULT_ROOM)
+}
+
+/// Mutably borrows the installed Bevy `App` of the `DEFAULT_ROOM`.
+///
+/// Fails if no app is installed, or if the app is already borrowed (re-entrant access).
+pub fn borrow_app() -> Result<AppGuard, String> {
+    borrow_room_app(DEFAULT_ROOM)
+}
+
+/// Runs `f` with a mutable borrow of the installed Bevy `App` of the `DEFAULT_ROOM`.
+/// Fails like `borrow_app` without calling `f`.
+pub fn with_app<R>(f: impl FnOnce(&mut App) -> R) -> Result<R, String> {
+    with_room_app(DEFAULT_ROOM, f)
+}
@@ -0,0 +1,67 @@
+// External Crate Imports
+use bevy::prelude::*;
+use spacetimedb::ReducerContext;
+
+// Crate Imports
+use crate::db::with_reducer_scope;
+use crate::{
+    borrow_room_app, process_bevy_commands_with_policy, BevyWorldAction, CurrentReducerMeta,
+    DbErrorPolicy, ReducerBackend, ReducerMeta, RoomId, DEFAULT_ROOM,
+};
+
+// --- Public API: Functions ---
+
+/// Runs `f` against the Bevy `World` of the `DEFAULT_ROOM` immediately, inside the calling reducer.
+///
+/// Unlike `schedule_bevy_action_from`, nothing is deferred to the next tick: after `f`
+/// returns, pending Bevy commands are applied and the `CommandQueue` is drained within
+/// `ctx`, so the resulting database writes land in the reducer's own transaction.
+/// `ReducerMeta` for `reducer` is available in `CurrentReducerMeta` while `f` runs,
+/// and systems run by `f` can read the database through `StdbDb`.
+///
+/// Returns the error of `f`, or the first failing `DbCommand` (regardless of the
+/// configured `DbErrorPolicy`), so the reducer can fail and roll back its writes.
+/// Changes made to the Bevy world itself are not rolled back.
+pub fn run_bevy_now<R>(
+    ctx: &ReducerContext,
+    reducer: &'static str,
+    f: impl FnOnce(&mut World) -> Result<R, String>,
+) -> Result<R, String> {
+    run_room_now(DEFAULT_ROOM, ctx, reducer, f)
+}
+
+/// Runs `f` against the Bevy `World` of `room` immediately, like `run_bevy_now`.
+pub fn run_room_now<R>(
+    room: RoomId,
+    ctx: &ReducerContext,
+    reducer: &'static str,
+    f: impl FnOnce(&mut World) -> Result<R, String>,
+) -> Result<R, String> {
+    let mut app = borrow_room_app(room)?;
+    let world = app.world_mut();
+
+    world.insert_resource(CurrentReducerMeta(Some(ReducerMeta::new(ctx, reducer))));
+    let result = with_reducer_scope(world, ctx, |world| {
+        let result = f(world);
+        world.flush(); // Apply commands queued through `world.commands()`.
+        result
+    });
+    world.insert_resource(CurrentReducerMeta(None));
+    let value = result?;
+
+    process_bevy_commands_with_policy(&mut *app, &mut ReducerBackend::new(ctx), DbErrorPolicy::FailTick)?;
+    log::trace!("Immediate Bevy execution in room {} for reducer '{}' completed.", room, reducer);
+    Ok(value)
+}
+
+/// Executes `action` immediately with `run_bevy_now`, instead of scheduling it for the next tick.
+pub fn execute_bevy_action_now(
+    ctx: &ReducerContext,
+    reducer: &'static str,
+    action: Box<dyn BevyWorldAction>,
+) -> Result<(), String> {
+    run_bevy_now(ctx, reducer, |world| {
+        action.execute(world);
+        Ok(())
+    })
+}
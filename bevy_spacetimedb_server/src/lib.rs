@@ -1,13 +1,46 @@
 // Standard Library Imports
-// (None currently)
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 // External Crate Imports
+use bevy::ecs::system::{SystemName, SystemParam};
 use bevy::prelude::*;
 use spacetimedb::ReducerContext;
 use bevy::ecs::prelude::Resource;
 use once_cell::sync::Lazy;
 use spin::Mutex;
 
+// --- Modules ---
+
+mod actions;
+mod app_ext;
+mod backend;
+mod coalesce;
+mod db;
+mod entity_map;
+mod host;
+mod immediate;
+mod meta;
+mod rate_limit;
+mod mirror;
+mod rehydrate;
+mod schedules;
+mod time;
+
+pub use actions::*;
+pub use app_ext::*;
+pub use backend::*;
+pub use db::*;
+pub use entity_map::*;
+pub use host::*;
+pub use immediate::*;
+pub use meta::*;
+pub use rate_limit::*;
+pub use mirror::*;
+pub use rehydrate::*;
+pub use schedules::*;
+pub use time::*;
+
 // --- Public API: Types and Components ---
 
 /// Re-export Bevy's Component trait for convenience.
@@ -15,32 +48,288 @@ pub use bevy::ecs::component::Component;
 
 /// Component used to link a Bevy `Entity` to its corresponding primary key
 /// in a SpacetimeDB table (e.g., `Entity::entity_id`).
+/// The same link is kept in the `StdbEntityMap` resource for lookups by row ID.
 ///
 /// This example uses `u32`, assuming the SpacetimeDB primary key is `u32`.
 /// It could be made generic or use a different type if needed.
 #[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SpacetimeId(pub u32);
 
-/// Type alias for a closure intended to be executed within a SpacetimeDB reducer context.
+/// Type alias for a closure intended to be executed against a `DbBackend`, normally the
+/// `ReducerBackend` of a SpacetimeDB reducer context.
 ///
 /// This allows Bevy systems to queue database operations.
 /// - `Ok(Some(new_id))`: Indicates a successful operation that created a new SpacetimeDB entity
 ///   with the given `new_id`. The integration layer might use this to link entities.
 /// - `Ok(None)`: Indicates a successful operation that didn't create a new entity (e.g., an update).
 /// - `Err(String)`: Indicates the operation failed.
-pub type DbCommandClosure = Box<dyn FnOnce(&ReducerContext) -> Result<Option<u32>, String> + Send + Sync>;
+pub type DbCommandClosure = Box<dyn FnOnce(&mut dyn DbBackend) -> Result<Option<u32>, String> + Send + Sync>;
+
+/// A write against a single SpacetimeDB table row, addressed by table name and primary key.
+///
+/// The `apply` closure performs the actual write; `table` and `key` describe its target
+/// so the integration layer can inspect, log and coalesce commands before running them.
+///
+/// Writes carry the full row: an insert, update or upsert closure must write every column
+/// of the row, never a partial patch. Coalescing relies on this to keep only the last write
+/// per `(table, key)` each tick without losing data.
+pub struct TableWrite {
+    /// Name of the SpacetimeDB table being written.
+    pub table: &'static str,
+    /// Primary key of the targeted row.
+    pub key: u32,
+    /// Closure performing the write against the backend.
+    pub apply: DbCommandClosure,
+}
 
 /// Internal representation of a command queued from Bevy to be executed in SpacetimeDB.
-/// Currently, only supports executing closures.
+///
+/// Table operations use the typed variants; `ExecuteClosure` remains as an escape hatch
+/// for opaque work that does not target a single row.
 pub enum DbCommand {
+    /// Inserts a new row.
+    Insert(TableWrite),
+    /// Updates an existing row.
+    Update(TableWrite),
+    /// Inserts the row, or updates it if it already exists.
+    Upsert(TableWrite),
+    /// Deletes a row.
+    Delete(TableWrite),
+    /// Runs an arbitrary closure; not inspected by the integration layer.
     ExecuteClosure(DbCommandClosure),
 }
 
+impl DbCommand {
+    /// Creates an `Insert` command for the row `key` of `table`.
+    pub fn insert(
+        table: &'static str,
+        key: u32,
+        apply: impl FnOnce(&mut dyn DbBackend) -> Result<Option<u32>, String> + Send + Sync + 'static,
+    ) -> Self {
+        DbCommand::Insert(TableWrite { table, key, apply: Box::new(apply) })
+    }
+
+    /// Creates an `Update` command for the row `key` of `table`.
+    ///
+    /// `apply` must write the complete row; earlier writes to the same row in the tick are dropped.
+    pub fn update(
+        table: &'static str,
+        key: u32,
+        apply: impl FnOnce(&mut dyn DbBackend) -> Result<Option<u32>, String> + Send + Sync + 'static,
+    ) -> Self {
+        DbCommand::Update(TableWrite { table, key, apply: Box::new(apply) })
+    }
+
+    /// Creates an `Upsert` command for the row `key` of `table`.
+    ///
+    /// `apply` must write the complete row; earlier writes to the same row in the tick are dropped.
+    pub fn upsert(
+        table: &'static str,
+        key: u32,
+        apply: impl FnOnce(&mut dyn DbBackend) -> Result<Option<u32>, String> + Send + Sync + 'static,
+    ) -> Self {
+        DbCommand::Upsert(TableWrite { table, key, apply: Box::new(apply) })
+    }
+
+    /// Creates a `Delete` command for the row `key` of `table`.
+    pub fn delete(
+        table: &'static str,
+        key: u32,
+        apply: impl FnOnce(&mut dyn DbBackend) -> Result<Option<u32>, String> + Send + Sync + 'static,
+    ) -> Self {
+        DbCommand::Delete(TableWrite { table, key, apply: Box::new(apply) })
+    }
+
+    /// The kind of operation performed by the command.
+    pub fn kind(&self) -> DbCommandKind {
+        match self {
+            DbCommand::Insert(_) => DbCommandKind::Insert,
+            DbCommand::Update(_) => DbCommandKind::Update,
+            DbCommand::Upsert(_) => DbCommandKind::Upsert,
+            DbCommand::Delete(_) => DbCommandKind::Delete,
+            DbCommand::ExecuteClosure(_) => DbCommandKind::Closure,
+        }
+    }
+
+    /// Returns the `(table, key)` targeted by a typed command, or `None` for closures.
+    pub fn target(&self) -> Option<(&'static str, u32)> {
+        match self {
+            DbCommand::Insert(w) | DbCommand::Update(w) | DbCommand::Upsert(w) | DbCommand::Delete(w) => {
+                Some((w.table, w.key))
+            }
+            DbCommand::ExecuteClosure(_) => None,
+        }
+    }
+
+    // Consumes the command, returning the closure that performs it.
+    fn into_closure(self) -> DbCommandClosure {
+        match self {
+            DbCommand::Insert(w) | DbCommand::Update(w) | DbCommand::Upsert(w) | DbCommand::Delete(w) => w.apply,
+            DbCommand::ExecuteClosure(closure) => closure,
+        }
+    }
+}
+
+/// The kind of operation of a `DbCommand`, e.g. reported in `DbCommandFailed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DbCommandKind {
+    /// `DbCommand::Insert`.
+    Insert,
+    /// `DbCommand::Update`.
+    Update,
+    /// `DbCommand::Upsert`.
+    Upsert,
+    /// `DbCommand::Delete`.
+    Delete,
+    /// `DbCommand::ExecuteClosure`.
+    Closure,
+}
+
+impl DbCommandKind {
+    /// Short name of the operation, used for logging.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DbCommandKind::Insert => "insert",
+            DbCommandKind::Update => "update",
+            DbCommandKind::Upsert => "upsert",
+            DbCommandKind::Delete => "delete",
+            DbCommandKind::Closure => "closure",
+        }
+    }
+}
+
+impl std::fmt::Display for DbCommandKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A `DbCommand` together with the name of the Bevy system that queued it.
+pub struct QueuedDbCommand {
+    /// The command to execute.
+    pub command: DbCommand,
+    /// Name of the system (or other origin) that queued the command.
+    pub source: Cow<'static, str>,
+    /// The Bevy entity the command was queued for, if any.
+    /// If the command reports `Ok(Some(id))`, `SpacetimeId(id)` is inserted on it.
+    pub entity: Option<Entity>,
+}
+
+// A queued command that executed successfully during `process_bevy_commands`.
+struct ExecutedDbCommand {
+    source: Cow<'static, str>,
+    kind: DbCommandKind,
+    target: Option<(&'static str, u32)>,
+    entity: Option<Entity>,
+    spawned_id: Option<u32>,
+}
+
 /// Bevy `Resource` that acts as a queue for `DbCommand` instances.
 /// Bevy systems add commands to this queue, and they are processed
 /// within a SpacetimeDB reducer context (typically `process_tick`).
 #[derive(Resource, Default)]
-pub struct CommandQueue(pub Vec<DbCommand>);
+pub struct CommandQueue(pub Vec<QueuedDbCommand>);
+
+impl CommandQueue {
+    /// Queues `command`, recording `source` as its origin.
+    pub fn push(&mut self, source: impl Into<Cow<'static, str>>, command: DbCommand) {
+        self.0.push(QueuedDbCommand { command, source: source.into(), entity: None });
+    }
+
+    /// Queues `command` on behalf of the Bevy `entity`, recording `source` as its origin.
+    pub fn push_for(&mut self, source: impl Into<Cow<'static, str>>, entity: Entity, command: DbCommand) {
+        self.0.push(QueuedDbCommand { command, source: source.into(), entity: Some(entity) });
+    }
+}
+
+/// `SystemParam` for queuing `DbCommand`s from a Bevy system.
+///
+/// Commands pushed through it are tagged with the running system's name, which is
+/// reported back in `DbCommandFailed` if the command fails.
+#[derive(SystemParam)]
+pub struct StdbCommands<'w, 's> {
+    queue: ResMut<'w, CommandQueue>,
+    system: SystemName<'s>,
+}
+
+impl StdbCommands<'_, '_> {
+    /// Queues `command` for execution in the next `process_bevy_commands` call.
+    pub fn push(&mut self, command: DbCommand) {
+        let source = self.system.name().to_string();
+        self.queue.push(source, command);
+    }
+
+    /// Queues `command` on behalf of the Bevy `entity`.
+    /// If the command reports `Ok(Some(id))`, `SpacetimeId(id)` is inserted on `entity`
+    /// once the command has been applied.
+    pub fn push_for(&mut self, entity: Entity, command: DbCommand) {
+        let source = self.system.name().to_string();
+        self.queue.push_for(source, entity, command);
+    }
+}
+
+/// How `process_bevy_commands` reacts when a command returns an error.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DbErrorPolicy {
+    /// Log the error and keep executing the remaining commands.
+    #[default]
+    Continue,
+    /// Stop at the first error; remaining commands of the tick are dropped
+    /// (and reported as such), while already executed commands are kept.
+    StopOnError,
+    /// Stop at the first error and return it, so the tick reducer fails and
+    /// SpacetimeDB rolls back the whole transaction. Dropped and rolled-back
+    /// commands are reported as well.
+    FailTick,
+}
+
+/// Counts reported by `process_bevy_commands` for one call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CommandStats {
+    /// Commands found in the `CommandQueue`.
+    pub queued: usize,
+    /// Commands dropped by coalescing writes to the same row.
+    pub coalesced: usize,
+    /// Commands that ran successfully.
+    pub executed: usize,
+    /// Commands that returned an error.
+    pub failed: usize,
+    /// Commands never run because an earlier command failed; see `DbErrorPolicy`.
+    pub dropped: usize,
+}
+
+/// Why a queued `DbCommand` did not take effect, as reported in `DbCommandFailed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DbFailureCause {
+    /// The command itself returned an error.
+    Failed,
+    /// The command was never executed because an earlier command failed
+    /// under `DbErrorPolicy::StopOnError` or `DbErrorPolicy::FailTick`.
+    Dropped,
+    /// The command executed, but a later failure under `DbErrorPolicy::FailTick`
+    /// rolled back the whole tick transaction.
+    RolledBack,
+}
+
+/// Bevy event sent for every queued `DbCommand` that did not take effect: the failing
+/// command itself, and under the stopping policies the dropped and rolled-back ones.
+/// It is sent after `process_bevy_commands` and can be read in the next update.
+#[derive(Event, Debug, Clone)]
+pub struct DbCommandFailed {
+    /// The error returned by the command, or the error that caused it to be dropped
+    /// or rolled back.
+    pub error: String,
+    /// Why the command did not take effect.
+    pub cause: DbFailureCause,
+    /// Name of the system that queued the command.
+    pub source: Cow<'static, str>,
+    /// The kind of operation.
+    pub kind: DbCommandKind,
+    /// The `(table, key)` targeted by the command, if it was a typed table operation.
+    pub target: Option<(&'static str, u32)>,
+    /// The Bevy entity the command was queued for, if any.
+    pub entity: Option<Entity>,
+}
 
 /// Trait for actions that need to be executed on the Bevy `World` from outside
 /// the main Bevy schedule, typically queued from SpacetimeDB reducers.
@@ -54,18 +343,18 @@ pub trait BevyWorldAction: Send + Sync {
 
 // --- Internal State ---
 
-// Static, mutex-protected buffer holding `BevyWorldAction`s queued from SpacetimeDB reducers.
-// These actions are processed before the next Bevy `App::update()` call.
-static PENDING_BEVY_ACTIONS: Lazy<Mutex<Vec<Box<dyn BevyWorldAction>>>> =
-    Lazy::new(|| Mutex::new(Vec::new()));
-
-// Placeholder component used to ensure the first spawned Bevy entity has index 1,
-// matching typical database auto-increment starting points.
-#[derive(Component)]
-struct Placeholder;
+// A scheduled `BevyWorldAction`, ordered by its sequence number and optionally
+// carrying the metadata of the reducer that scheduled it.
+struct PendingAction {
+    seq: u64,
+    meta: Option<ReducerMeta>,
+    action: Box<dyn BevyWorldAction>,
+}
 
-// Concrete implementation of `BevyWorldAction` for sending a Bevy `Event`.
-struct SendBevyEvent<T: Event + Clone + Send + Sync>(T);
+// Static, mutex-protected buffers holding `BevyWorldAction`s queued from SpacetimeDB reducers,
+// one per room. These actions are processed before the room's next Bevy `App::update()` call.
+static PENDING_BEVY_ACTIONS: Lazy<Mutex<HashMap<RoomId, Vec<PendingAction>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 
 // --- Bevy Plugin ---
 
@@ -73,145 +362,459 @@ struct SendBevyEvent<T: Event + Clone + Send + Sync>(T);
 ///
 /// Initializes necessary resources like the `CommandQueue` and adds
 /// systems required for the integration.
-pub struct SpacetimeDbServerPlugin;
+#[derive(Default)]
+pub struct SpacetimeDbServerPlugin {
+    /// How `process_bevy_commands` handles failing commands.
+    pub error_policy: DbErrorPolicy,
+}
+
+impl SpacetimeDbServerPlugin {
+    /// Sets the policy applied when a queued `DbCommand` fails.
+    pub fn with_error_policy(mut self, error_policy: DbErrorPolicy) -> Self {
+        self.error_policy = error_policy;
+        self
+    }
+}
 
 impl Plugin for SpacetimeDbServerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CommandQueue>()
-           // Add a system to spawn a placeholder entity at startup.
-           .add_systems(PreStartup, add_single_entity_system);
-        log::info!("SpacetimeDbServerPlugin initialized: CommandQueue resource added.");
+           .init_resource::<StdbEntityMap>()
+           .insert_resource(self.error_policy)
+           .init_resource::<CurrentReducerMeta>()
+           .init_resource::<TickSchedules>()
+           .add_event::<DbCommandFailed>()
+           // Unlink despawned entities once mirroring has seen their removal.
+           .add_systems(Last, unmap_despawned_entities_system)
+           // Drive Bevy's clocks from SpacetimeDB tick timestamps.
+           .add_plugins(time::StdbTimePlugin);
+        log::info!("SpacetimeDbServerPlugin initialized: CommandQueue and StdbEntityMap resources added.");
     }
 }
 
-// --- Bevy Systems ---
-
-/// System run during `PreStartup` to spawn a single placeholder entity.
-/// This ensures subsequent Bevy-managed entities start with index 1,
-/// aligning potentially with SpacetimeDB primary key generation.
-pub fn add_single_entity_system(mut commands: Commands) {
-    commands.spawn(Placeholder);
-    log::trace!("Placeholder entity spawned to ensure entity IDs start from 1.");
-}
-
 // --- Public API: Functions ---
 
 /// Executes a single update cycle of the provided Bevy `App`.
 /// This should typically be called from the SpacetimeDB tick reducer.
-pub fn run_bevy_update(app: &mut App) -> Result<(), String> {
-    app.update();
+///
+/// The time elapsed since the previous tick's `ctx.timestamp` becomes the `Time` delta,
+/// and `FixedUpdate` runs once per elapsed fixed timestep.
+/// The schedules listed in `TickSchedules` are run in order (by default just `Main`,
+/// as `App::update` would); see `StdbAppExt::add_tick_schedule`.
+/// Systems can read the database through `StdbDb` while the schedules run.
+pub fn run_bevy_update(app: &mut App, ctx: &ReducerContext) -> Result<(), String> {
+    time::advance_time(app.world_mut(), ctx.timestamp);
+    db::with_reducer_scope(app.world_mut(), ctx, schedules::run_tick_schedules);
     Ok(())
 }
 
 /// Processes all commands currently in the `CommandQueue` resource.
 ///
-/// This function takes commands queued by Bevy systems and executes their
-/// associated closures within the provided SpacetimeDB `ReducerContext`.
+/// This function takes commands queued by Bevy systems, coalesces writes that
+/// target the same row (last write wins, insert+delete cancel out), and executes
+/// their associated closures within the provided SpacetimeDB `ReducerContext`.
 /// It requires mutable access to the Bevy `App` to retrieve the queue.
 /// This should typically be called from the SpacetimeDB tick reducer after `run_bevy_update`.
-pub fn process_bevy_commands(app: &mut App, ctx: &ReducerContext) -> Result<(), String> {
+///
+/// Failing commands are handled according to the `DbErrorPolicy` resource and
+/// reported to the Bevy world as `DbCommandFailed` events. An error is only
+/// returned under `DbErrorPolicy::FailTick`.
+///
+/// Commands queued for an entity that report `Ok(Some(id))` get `SpacetimeId(id)`
+/// inserted on that entity, unless the tick is failed and rolled back.
+/// Returns the `CommandStats` of the call.
+pub fn process_bevy_commands(app: &mut App, ctx: &ReducerContext) -> Result<CommandStats, String> {
+    process_bevy_commands_with(app, &mut ReducerBackend::new(ctx))
+}
+
+/// Processes the `CommandQueue` like `process_bevy_commands`, against any `DbBackend`,
+/// e.g. an `InMemoryDb` in unit tests.
+pub fn process_bevy_commands_with(app: &mut App, backend: &mut dyn DbBackend) -> Result<CommandStats, String> {
+    let policy = app
+        .world()
+        .get_resource::<DbErrorPolicy>()
+        .copied()
+        .unwrap_or_default();
+    process_bevy_commands_with_policy(app, backend, policy)
+}
+
+// Implementation of `process_bevy_commands` with an explicit error policy,
+// shared with immediate-mode execution.
+pub(crate) fn process_bevy_commands_with_policy(
+    app: &mut App,
+    backend: &mut dyn DbBackend,
+    policy: DbErrorPolicy,
+) -> Result<CommandStats, String> {
     // Extract commands from the queue within the app's world.
-    let commands_to_process: Vec<DbCommand> = {
+    let commands_to_process: Vec<QueuedDbCommand> = {
         let mut command_queue = app.world_mut().resource_mut::<CommandQueue>();
         // Drain the queue to take ownership of the commands.
         command_queue.0.drain(..).collect()
     };
 
     if commands_to_process.is_empty() {
-        return Ok(()); // No commands to process.
-    }
-
-    log::debug!("Processing {} Bevy->SpacetimeDB commands...", commands_to_process.len());
-    for command in commands_to_process {
-        match command {
-            DbCommand::ExecuteClosure(closure) => {
-                log::trace!("Executing SpacetimeDB command closure...");
-                match closure(ctx) {
-                    Ok(Some(new_entity_id)) => {
-                        // A new entity was created in SpacetimeDB.
-                        // Future enhancement: Could potentially queue a Bevy action
-                        // here to add the SpacetimeId component to the corresponding Bevy entity.
-                        log::trace!("Closure reported SpacetimeDB spawn with ID: {}", new_entity_id);
-                    }
-                    Ok(None) => {
-                        // Closure executed successfully, no new entity reported.
-                        log::trace!("SpacetimeDB command closure executed successfully (no spawn reported).");
+        return Ok(CommandStats::default()); // No commands to process.
+    }
+
+    // Merge writes to the same row so each row is touched at most once per tick.
+    let queued = commands_to_process.len();
+    let commands_to_process = coalesce::coalesce_commands(commands_to_process);
+    let mut stats = CommandStats {
+        queued,
+        coalesced: queued - commands_to_process.len(),
+        ..Default::default()
+    };
+    log::debug!(
+        "Processing {} Bevy->SpacetimeDB commands ({} coalesced away)...",
+        commands_to_process.len(),
+        stats.coalesced
+    );
+    let mut failures: Vec<DbCommandFailed> = Vec::new();
+    // Commands executed so far; reported as rolled back if the tick fails.
+    let mut executed: Vec<ExecutedDbCommand> = Vec::new();
+    let mut result = Ok(());
+    let mut commands = commands_to_process.into_iter();
+    while let Some(QueuedDbCommand { command, source, entity }) = commands.next() {
+        let kind = command.kind();
+        let target = command.target();
+        match target {
+            Some((table, key)) => {
+                log::trace!("Executing SpacetimeDB {} on '{}' row {}...", kind, table, key)
+            }
+            None => log::trace!("Executing SpacetimeDB command closure..."),
+        }
+        let closure = command.into_closure();
+        match closure(backend) {
+            Ok(spawned_id) => {
+                stats.executed += 1;
+                match spawned_id {
+                    // A new entity was created in SpacetimeDB.
+                    // It is linked to the originating Bevy entity once all commands have run.
+                    Some(new_entity_id) => {
+                        log::trace!("Command reported SpacetimeDB spawn with ID: {}", new_entity_id)
                     }
-                    Err(e) => {
-                        // The closure returned an error.
-                        log::error!("Error executing SpacetimeDB command closure: {}", e);
-                        // Consider whether one error should halt processing of subsequent commands.
-                        // return Err(e); // Uncomment to halt on first error.
+                    // Command executed successfully, no new entity reported.
+                    None => log::trace!("SpacetimeDB command executed successfully (no spawn reported)."),
+                }
+                executed.push(ExecutedDbCommand { source, kind, target, entity, spawned_id });
+            }
+            Err(e) => {
+                // The command returned an error.
+                stats.failed += 1;
+                log::error!("Error executing SpacetimeDB {} queued by '{}': {}", kind, source, e);
+                failures.push(DbCommandFailed {
+                    error: e.clone(),
+                    cause: DbFailureCause::Failed,
+                    source,
+                    kind,
+                    target,
+                    entity,
+                });
+                if policy == DbErrorPolicy::Continue {
+                    continue;
+                }
+
+                // The remaining commands never run; report each of them.
+                stats.dropped = commands.len();
+                log::warn!("Stopping command processing; dropping {} remaining commands.", stats.dropped);
+                for QueuedDbCommand { command, source, entity } in commands.by_ref() {
+                    failures.push(DbCommandFailed {
+                        error: format!("dropped after an earlier command failed: {}", e),
+                        cause: DbFailureCause::Dropped,
+                        source,
+                        kind: command.kind(),
+                        target: command.target(),
+                        entity,
+                    });
+                }
+                if policy == DbErrorPolicy::FailTick {
+                    // The reducer transaction rolls back, undoing everything executed this tick,
+                    // including inserts whose Bevy entities would otherwise be linked below.
+                    log::warn!("Failing tick; rolling back {} executed commands.", executed.len());
+                    for ExecutedDbCommand { source, kind, target, entity, .. } in executed.drain(..) {
+                        failures.push(DbCommandFailed {
+                            error: format!("rolled back after a later command failed: {}", e),
+                            cause: DbFailureCause::RolledBack,
+                            source,
+                            kind,
+                            target,
+                            entity,
+                        });
                     }
+                    result = Err(e);
                 }
+                break;
+            }
+        }
+    }
+
+    let world = app.world_mut();
+    // Link spawned rows to their Bevy entities; empty if the tick is rolled back.
+    for ExecutedDbCommand { entity, spawned_id, .. } in executed {
+        let Some(id) = spawned_id else { continue };
+        let Some(entity) = entity else {
+            log::trace!("No Bevy entity attached to command; SpacetimeId {} not linked.", id);
+            continue;
+        };
+        match world.get_entity_mut(entity) {
+            Ok(mut entity_mut) => {
+                entity_mut.insert(SpacetimeId(id));
+                log::trace!("Linked Bevy entity {} to SpacetimeId {}.", entity, id);
             }
+            Err(_) => {
+                log::warn!("Bevy entity {} despawned before SpacetimeId {} could be linked.", entity, id);
+                continue;
+            }
+        }
+        if let Some(mut entity_map) = world.get_resource_mut::<StdbEntityMap>() {
+            entity_map.insert(entity, id);
         }
     }
+
+    // Report failures back into the Bevy world so game logic can react.
+    if !failures.is_empty() {
+        world.send_event_batch(failures);
+    }
     log::debug!("Finished processing Bevy->SpacetimeDB commands.");
-    Ok(())
+    result.map(|()| stats)
 }
 
-/// Schedules a `BevyWorldAction` to be executed on the Bevy `World`
-/// before the next `run_bevy_update` call.
+/// Schedules a `BevyWorldAction` to be executed on the Bevy `World` of the
+/// `DEFAULT_ROOM` before the next `run_bevy_update` call.
 ///
 /// This function is designed to be called safely from SpacetimeDB reducers
 /// (which run on different threads/contexts than the main Bevy loop).
 /// It pushes the action onto the `PENDING_BEVY_ACTIONS` static queue.
 pub fn schedule_bevy_action(action: Box<dyn BevyWorldAction>) {
     log::trace!("Scheduling a BevyWorldAction.");
-    PENDING_BEVY_ACTIONS.lock().push(action);
+    let pending = PendingAction { seq: meta::next_action_seq(), meta: None, action };
+    PENDING_BEVY_ACTIONS.lock().entry(DEFAULT_ROOM).or_default().push(pending);
+}
+
+/// Schedules a `BevyWorldAction` like `schedule_bevy_action`, wrapped with the
+/// `ReducerMeta` of the calling reducer (`reducer` is its name).
+///
+/// While the action executes, the metadata is available in the `CurrentReducerMeta`
+/// resource, e.g. for actions created by `create_send_reducer_event_action`.
+pub fn schedule_bevy_action_from(ctx: &ReducerContext, reducer: &'static str, action: Box<dyn BevyWorldAction>) {
+    schedule_room_action_from(DEFAULT_ROOM, ctx, reducer, action);
 }
 
-/// Processes all pending actions stored in the `PENDING_BEVY_ACTIONS` queue.
+/// Schedules a `BevyWorldAction` like `schedule_bevy_action_from`, for the world of `room`.
+pub fn schedule_room_action_from(
+    room: RoomId,
+    ctx: &ReducerContext,
+    reducer: &'static str,
+    action: Box<dyn BevyWorldAction>,
+) {
+    let meta = ReducerMeta::new(ctx, reducer);
+    log::trace!(
+        "Scheduling a BevyWorldAction for room {} from reducer '{}' (seq {}).",
+        room,
+        reducer,
+        meta.seq
+    );
+    let pending = PendingAction { seq: meta.seq, meta: Some(meta), action };
+    PENDING_BEVY_ACTIONS.lock().entry(room).or_default().push(pending);
+}
+
+/// Processes all pending actions stored in the `PENDING_BEVY_ACTIONS` queue of the
+/// app's room (its `CurrentRoom`, or the `DEFAULT_ROOM` if it has none).
 ///
 /// This function should be called within the SpacetimeDB tick reducer context,
 /// *before* calling `run_bevy_update`. It requires mutable access to the
 /// Bevy `App` to get access to the `World`.
-/// It drains the queue and executes each action against the `World`.
-pub fn process_bevy_actions(app: &mut App) {
-    let actions_to_process: Vec<Box<dyn BevyWorldAction>> = {
+/// It drains the queue and executes each action against the `World` in sequence order,
+/// exposing its `ReducerMeta` (if any) through the `CurrentReducerMeta` resource.
+/// Returns the number of executed actions.
+pub fn process_bevy_actions(app: &mut App) -> usize {
+    let room = app
+        .world()
+        .get_resource::<CurrentRoom>()
+        .map_or(DEFAULT_ROOM, |current| current.0);
+    let mut actions_to_process: Vec<PendingAction> = {
         // Attempt to acquire the lock non-blockingly.
         // In the unlikely event of contention (e.g., if called concurrently),
         // skip processing for this tick to avoid deadlocks.
         match PENDING_BEVY_ACTIONS.try_lock() {
-            Some(mut guard) => guard.drain(..).collect(),
+            Some(mut guard) => guard.remove(&room).unwrap_or_default(),
             None => {
                 log::warn!("Could not acquire PENDING_BEVY_ACTIONS lock; skipping SpacetimeDB->Bevy action processing this tick.");
-                return; // Skip processing if lock is busy.
+                return 0; // Skip processing if lock is busy.
             }
         }
     };
 
     if actions_to_process.is_empty() {
-        return; // No actions scheduled.
+        return 0; // No actions scheduled.
     }
 
-    log::debug!("Processing {} SpacetimeDB->Bevy actions...", actions_to_process.len());
+    let count = actions_to_process.len();
+    log::debug!("Processing {} SpacetimeDB->Bevy actions...", count);
+    actions_to_process.sort_by_key(|pending| pending.seq);
 
     // Get mutable access to the world once for efficiency.
     let world = app.world_mut();
-    for action in actions_to_process {
-        log::trace!("Executing scheduled BevyWorldAction.");
+    for PendingAction { seq, meta, action } in actions_to_process {
+        log::trace!("Executing scheduled BevyWorldAction (seq {}).", seq);
+        world.insert_resource(CurrentReducerMeta(meta));
         action.execute(world); // Execute the action (e.g., world.send_event).
     }
+    world.insert_resource(CurrentReducerMeta(None));
     log::debug!("Finished processing SpacetimeDB->Bevy actions.");
+    count
 }
 
-/// Helper function to create a boxed `BevyWorldAction` specifically for sending a Bevy `Event`.
-/// The event type `T` must implement `Event`, `Clone`, `Send`, `Sync`, and be `'static`.
-pub fn create_send_event_action<T: Event + Clone + Send + Sync + 'static>(event: T) -> Box<dyn BevyWorldAction> {
-    log::trace!("Creating SendBevyEvent action for event type: {}", std::any::type_name::<T>());
-    Box::new(SendBevyEvent(event))
-}
+// ------------------------------ 
 
-// --- Trait Implementations ---
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl<T: Event + Clone + Send + Sync> BevyWorldAction for SendBevyEvent<T> {
-    fn execute(&self, world: &mut World) {
-        // Clone the event data to send it into the Bevy event system.
-        world.send_event(self.0.clone());
-        log::trace!("Executed SendBevyEvent action (sent event).");
+    // Row type stored only in an `InMemoryDb`, which never calls the reducer functions.
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestRow {
+        id: u32,
+        value: i32,
     }
-}
 
-// ------------------------------ 
\ No newline at end of file
+    impl StdbReadRow for TestRow {
+        type Key = u32;
+
+        fn find(_ctx: &ReducerContext, _key: &u32) -> Option<Self> {
+            unreachable!("tests only use InMemoryDb")
+        }
+
+        fn all(_ctx: &ReducerContext) -> Vec<Self> {
+            unreachable!("tests only use InMemoryDb")
+        }
+    }
+
+    impl StdbRow for TestRow {
+        const TABLE: &'static str = "test_row";
+
+        fn key(&self) -> u32 {
+            self.id
+        }
+
+        fn insert(_ctx: &ReducerContext, _row: Self) -> Result<(), String> {
+            unreachable!("tests only use InMemoryDb")
+        }
+
+        fn update(_ctx: &ReducerContext, _row: Self) -> bool {
+            unreachable!("tests only use InMemoryDb")
+        }
+
+        fn delete(_ctx: &ReducerContext, _key: u32) -> bool {
+            unreachable!("tests only use InMemoryDb")
+        }
+    }
+
+    // Queues the insert of `row` for `entity`, reporting the row's key as spawned.
+    fn queue_insert(app: &mut App, entity: Entity, row: TestRow) {
+        let id = row.id;
+        let command = DbCommand::insert(TestRow::TABLE, id, move |db| {
+            db.insert(row)?;
+            Ok(Some(id))
+        });
+        app.world_mut().resource_mut::<CommandQueue>().push_for("test", entity, command);
+    }
+
+    fn failures(app: &App) -> Vec<DbCommandFailed> {
+        let events = app.world().resource::<Events<DbCommandFailed>>();
+        events.iter_current_update_events().cloned().collect()
+    }
+
+    // Spawns three entities and queues inserts of rows 6, 7 and 8, where row 7 already exists.
+    fn queue_inserts_failing_in_the_middle(app: &mut App) -> [Entity; 3] {
+        let entities = [(); 3].map(|_| app.world_mut().spawn_empty().id());
+        for (entity, id) in entities.into_iter().zip(6..) {
+            queue_insert(app, entity, TestRow { id, value: 2 });
+        }
+        entities
+    }
+
+    #[test]
+    fn inserts_rows_and_links_spacetime_ids() {
+        let mut app = App::new();
+        app.add_plugins(SpacetimeDbServerPlugin::default());
+        let entity = app.world_mut().spawn_empty().id();
+        queue_insert(&mut app, entity, TestRow { id: 7, value: 1 });
+
+        let mut db = InMemoryDb::new();
+        process_bevy_commands_with(&mut app, &mut db).unwrap();
+
+        assert_eq!(db.row::<TestRow>(7), Some(&TestRow { id: 7, value: 1 }));
+        assert_eq!(app.world().get::<SpacetimeId>(entity), Some(&SpacetimeId(7)));
+        assert_eq!(app.world().resource::<StdbEntityMap>().entity_of(7), Some(entity));
+        assert!(app.world().resource::<CommandQueue>().0.is_empty());
+    }
+
+    #[test]
+    fn failed_commands_are_reported() {
+        let mut app = App::new();
+        app.add_plugins(SpacetimeDbServerPlugin::default());
+        let [first, second, third] = queue_inserts_failing_in_the_middle(&mut app);
+
+        let mut db = InMemoryDb::new().with_row(TestRow { id: 7, value: 1 });
+        let stats = process_bevy_commands_with(&mut app, &mut db).unwrap();
+
+        // `Continue` runs the commands after the failure.
+        assert_eq!((stats.queued, stats.executed, stats.failed, stats.dropped), (3, 2, 1, 0));
+        assert_eq!(db.row::<TestRow>(7), Some(&TestRow { id: 7, value: 1 }));
+        assert_eq!(app.world().get::<SpacetimeId>(first), Some(&SpacetimeId(6)));
+        assert_eq!(app.world().get::<SpacetimeId>(second), None);
+        assert_eq!(app.world().get::<SpacetimeId>(third), Some(&SpacetimeId(8)));
+        let failures = failures(&app);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].cause, DbFailureCause::Failed);
+        assert_eq!(failures[0].kind, DbCommandKind::Insert);
+        assert_eq!(failures[0].target, Some((TestRow::TABLE, 7)));
+        assert_eq!(failures[0].entity, Some(second));
+        assert_eq!(failures[0].source, "test");
+    }
+
+    #[test]
+    fn stop_on_error_reports_dropped_commands() {
+        let mut app = App::new();
+        app.add_plugins(SpacetimeDbServerPlugin::default().with_error_policy(DbErrorPolicy::StopOnError));
+        let [first, second, third] = queue_inserts_failing_in_the_middle(&mut app);
+
+        let mut db = InMemoryDb::new().with_row(TestRow { id: 7, value: 1 });
+        let stats = process_bevy_commands_with(&mut app, &mut db).unwrap();
+
+        assert_eq!((stats.queued, stats.executed, stats.failed, stats.dropped), (3, 1, 1, 1));
+        assert_eq!(db.row::<TestRow>(8), None);
+        assert_eq!(app.world().get::<SpacetimeId>(first), Some(&SpacetimeId(6)));
+        assert_eq!(app.world().get::<SpacetimeId>(third), None);
+        let reported: Vec<_> = failures(&app).iter().map(|f| (f.cause, f.entity)).collect();
+        assert_eq!(
+            reported,
+            vec![(DbFailureCause::Failed, Some(second)), (DbFailureCause::Dropped, Some(third))]
+        );
+    }
+
+    #[test]
+    fn fail_tick_reports_dropped_and_rolled_back_commands() {
+        let mut app = App::new();
+        app.add_plugins(SpacetimeDbServerPlugin::default().with_error_policy(DbErrorPolicy::FailTick));
+        let [first, second, third] = queue_inserts_failing_in_the_middle(&mut app);
+
+        let mut db = InMemoryDb::new().with_row(TestRow { id: 7, value: 1 });
+        assert!(process_bevy_commands_with(&mut app, &mut db).is_err());
+
+        // The host rolls back the insert of row 6, so its entity is not linked.
+        assert_eq!(db.row::<TestRow>(8), None);
+        assert_eq!(app.world().get::<SpacetimeId>(first), None);
+        assert_eq!(app.world().resource::<StdbEntityMap>().entity_of(6), None);
+        let reported: Vec<_> = failures(&app).iter().map(|f| (f.cause, f.entity)).collect();
+        assert_eq!(
+            reported,
+            vec![
+                (DbFailureCause::Failed, Some(second)),
+                (DbFailureCause::Dropped, Some(third)),
+                (DbFailureCause::RolledBack, Some(first)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+// Standard Library Imports
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// External Crate Imports
+use bevy::prelude::*;
+use spacetimedb::{ConnectionId, Identity, ReducerContext, Timestamp};
+
+// Crate Imports
+use crate::BevyWorldAction;
+
+// --- Internal State ---
+
+// Source of monotonically increasing sequence numbers for scheduled actions.
+static NEXT_ACTION_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the next action sequence number.
+pub(crate) fn next_action_seq() -> u64 {
+    NEXT_ACTION_SEQ.fetch_add(1, Ordering::Relaxed)
+}
+
+// --- Public API: Types ---
+
+/// Metadata about the reducer call that scheduled a `BevyWorldAction`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReducerMeta {
+    /// `Identity` of the caller.
+    pub sender: Identity,
+    /// Connection of the caller, `None` for scheduled or lifecycle reducers.
+    pub connection_id: Option<ConnectionId>,
+    /// Timestamp of the reducer call.
+    pub timestamp: Timestamp,
+    /// Name of the reducer, as passed by the caller.
+    pub reducer: &'static str,
+    /// Sequence number of the scheduled action; actions run in increasing order.
+    pub seq: u64,
+}
+
+impl ReducerMeta {
+    /// Captures the metadata of the reducer running in `ctx`.
+    pub fn new(ctx: &ReducerContext, reducer: &'static str) -> Self {
+        Self {
+            sender: ctx.sender,
+            connection_id: ctx.connection_id,
+            timestamp: ctx.timestamp,
+            reducer,
+            seq: next_action_seq(),
+        }
+    }
+}
+
+/// Bevy `Resource` holding the metadata of the action currently being executed by
+/// `process_bevy_actions`. It is `None` outside of action execution and for actions
+/// scheduled without metadata.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CurrentReducerMeta(pub Option<ReducerMeta>);
+
+/// Bevy event wrapping an event of type `T` with the metadata of the reducer that sent it.
+/// Register it with `app.add_event::<FromReducer<T>>()`.
+#[derive(Event, Debug, Clone)]
+pub struct FromReducer<T: Send + Sync + 'static> {
+    /// Metadata of the reducer call that sent the event.
+    pub meta: ReducerMeta,
+    /// The wrapped event.
+    pub event: T,
+}
+
+// Concrete implementation of `BevyWorldAction` for sending `FromReducer<T>`
+// with the ambient `CurrentReducerMeta`.
+struct SendReducerEvent<T: Clone + Send + Sync + 'static>(T);
+
+// --- Public API: Functions ---
+
+/// Creates a boxed `BevyWorldAction` sending `FromReducer<T>`, carrying the metadata of the
+/// reducer that scheduled it. Schedule it with `schedule_bevy_action_from`; without metadata
+/// the event is dropped with a warning.
+pub fn create_send_reducer_event_action<T: Clone + Send + Sync + 'static>(event: T) -> Box<dyn BevyWorldAction> {
+    log::trace!("Creating SendReducerEvent action for event type: {}", std::any::type_name::<T>());
+    Box::new(SendReducerEvent(event))
+}
+
+// --- Trait Implementations ---
+
+impl<T: Clone + Send + Sync + 'static> BevyWorldAction for SendReducerEvent<T> {
+    fn execute(&self, world: &mut World) {
+        let Some(meta) = world.resource::<CurrentReducerMeta>().0.clone() else {
+            log::warn!(
+                "SendReducerEvent action for {} has no reducer metadata; event dropped.",
+                std::any::type_name::<T>()
+            );
+            return;
+        };
+        world.send_event(FromReducer { meta, event: self.0.clone() });
+        log::trace!("Executed SendReducerEvent action (sent event).");
+    }
+}
@@ -0,0 +1,172 @@
+// Standard Library Imports
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+// External Crate Imports
+use bevy::prelude::*;
+
+// Crate Imports
+use crate::{DbBackend, DbCommand, DbCommandFailed, StdbCommands, StdbEntityMap};
+
+// --- Public API: Traits ---
+
+/// Trait for Bevy components whose value is mirrored into a SpacetimeDB table row.
+///
+/// Implementors describe how a component value maps onto its table row; the
+/// `MirrorPlugin` takes care of detecting `Added`, `Changed` and removed components
+/// and queuing the matching insert/update/delete on the `CommandQueue`.
+///
+/// The row is addressed by its `u32` primary key, looked up in the `StdbEntityMap`.
+/// `insert_row` and `delete_row` default to no-ops for components that only own some
+/// columns of a row created and removed elsewhere (e.g. a position column).
+///
+/// Writes are coalesced per row each tick (last write wins), so `update_row` must write the
+/// complete row: read the current row, overwrite the mirrored columns and update it as a whole.
+///
+/// Values are passed through `quantize` before being written, and a change is only
+/// written if `exceeds_threshold` reports it moved far enough from the last written value.
+/// A skipped change is still written once the component stops changing, so the row
+/// settles on the final value.
+pub trait StdbMirror: Component + Clone {
+    /// Name of the mirrored SpacetimeDB table, used for logging.
+    const TABLE: &'static str;
+
+    /// Returns the value to write, e.g. rounded to the precision stored in the table.
+    /// Defaults to the value itself.
+    fn quantize(&self) -> Self {
+        self.clone()
+    }
+
+    /// Returns `true` if this (quantized) value differs enough from the `last_written`
+    /// one to be worth a row update. Defaults to always writing.
+    fn exceeds_threshold(&self, _last_written: &Self) -> bool {
+        true
+    }
+
+    /// Writes a newly added component value to the row with primary key `id`.
+    fn insert_row(_db: &mut dyn DbBackend, _id: u32, _value: &Self) -> Result<Option<u32>, String> {
+        Ok(None)
+    }
+
+    /// Writes a changed component value to the existing row with primary key `id`.
+    fn update_row(db: &mut dyn DbBackend, id: u32, value: &Self) -> Result<Option<u32>, String>;
+
+    /// Deletes the row with primary key `id` after the component was removed.
+    fn delete_row(_db: &mut dyn DbBackend, _id: u32) -> Result<Option<u32>, String> {
+        Ok(None)
+    }
+}
+
+// --- Bevy Plugin ---
+
+/// System set containing every mirroring system.
+/// Mirroring runs in `PostUpdate`, so it observes all changes made during `Update`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StdbMirrorSet;
+
+/// Bevy `Plugin` that mirrors component `C` into its SpacetimeDB table.
+///
+/// Requires `SpacetimeDbServerPlugin` for the `CommandQueue` resource.
+pub struct MirrorPlugin<C: StdbMirror>(PhantomData<C>);
+
+impl<C: StdbMirror> Default for MirrorPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: StdbMirror> Plugin for MirrorPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, mirror_component_system::<C>.in_set(StdbMirrorSet));
+        log::info!(
+            "MirrorPlugin initialized: {} -> table '{}'.",
+            std::any::type_name::<C>(),
+            C::TABLE
+        );
+    }
+}
+
+// --- Internal State ---
+
+// Value last written for an entity by `mirror_component_system`.
+struct MirrorState<C> {
+    written: C,
+    // Latest change skipped by the threshold, written once the component stops changing.
+    pending: Option<C>,
+}
+
+// --- Bevy Systems ---
+
+/// Bevy system that queues SpacetimeDB writes for added, changed and removed `C` components.
+/// The row primary key is looked up in the `StdbEntityMap`; unmapped entities are skipped.
+/// Changes within `C::exceeds_threshold` of the last written value are held back until the
+/// component stops changing, then written once.
+pub fn mirror_component_system<C: StdbMirror>(
+    query: Query<(Entity, Ref<C>), Changed<C>>,
+    mut removed: RemovedComponents<C>,
+    mut failures: EventReader<DbCommandFailed>,
+    entity_map: Res<StdbEntityMap>,
+    mut db_commands: StdbCommands,
+    mut mirrored: Local<HashMap<Entity, MirrorState<C>>>,
+) {
+    // A failed write leaves the row behind the last written value; write the next change as is.
+    for failure in failures.read() {
+        let Some(bevy_entity) = failure.entity else {
+            continue;
+        };
+        if failure.target.is_some_and(|(table, _)| table == C::TABLE) {
+            mirrored.remove(&bevy_entity);
+        }
+    }
+
+    for (bevy_entity, component) in query.iter() {
+        let Some(id) = entity_map.id_of(bevy_entity) else {
+            log::trace!("Bevy entity {} has no row ID; not mirroring {}.", bevy_entity, C::TABLE);
+            continue;
+        };
+        let value = component.quantize();
+        if !component.is_added() {
+            if let Some(state) = mirrored.get_mut(&bevy_entity) {
+                if !value.exceeds_threshold(&state.written) {
+                    state.pending = Some(value); // Not worth a row update yet.
+                    continue;
+                }
+            }
+        }
+        mirrored.insert(bevy_entity, MirrorState { written: value.clone(), pending: None });
+
+        let cmd = if component.is_added() {
+            log::trace!("Queuing STDB insert into '{}' for row {}.", C::TABLE, id);
+            DbCommand::insert(C::TABLE, id, move |db| C::insert_row(db, id, &value))
+        } else {
+            log::trace!("Queuing STDB update of '{}' for row {}.", C::TABLE, id);
+            DbCommand::update(C::TABLE, id, move |db| C::update_row(db, id, &value))
+        };
+        db_commands.push_for(bevy_entity, cmd);
+    }
+
+    for bevy_entity in removed.read() {
+        mirrored.remove(&bevy_entity);
+        let Some(id) = entity_map.id_of(bevy_entity) else {
+            continue;
+        };
+        log::trace!("Queuing STDB delete from '{}' for row {}.", C::TABLE, id);
+        db_commands.push_for(bevy_entity, DbCommand::delete(C::TABLE, id, move |db| C::delete_row(db, id)));
+    }
+
+    // Settle rows whose last change was held back, once their component stopped changing.
+    for (&bevy_entity, state) in mirrored.iter_mut() {
+        if state.pending.is_none() || query.contains(bevy_entity) {
+            continue;
+        }
+        let Some(id) = entity_map.id_of(bevy_entity) else {
+            continue;
+        };
+        let Some(value) = state.pending.take() else {
+            continue;
+        };
+        state.written = value.clone();
+        log::trace!("Queuing settling STDB update of '{}' for row {}.", C::TABLE, id);
+        db_commands.push_for(bevy_entity, DbCommand::update(C::TABLE, id, move |db| C::update_row(db, id, &value)));
+    }
+}
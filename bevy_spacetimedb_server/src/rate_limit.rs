@@ -0,0 +1,138 @@
+// Standard Library Imports
+use std::collections::HashMap;
+
+// External Crate Imports
+use spacetimedb::{Identity, Timestamp};
+
+// --- Public API: Types ---
+
+/// What `RateLimiter::admit` does with a call over budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverBudget {
+    /// Silently ignore the call; the reducer succeeds without doing anything.
+    #[default]
+    Drop,
+    /// Fail the call with an error.
+    Reject,
+}
+
+/// Per-`Identity` token bucket limiting how often a reducer may be called.
+///
+/// Each identity may make up to `burst` calls at once, and regains `per_second`
+/// calls per second of reducer time. Keep it in module memory, e.g. a
+/// `Lazy<Mutex<RateLimiter>>`, and call `forget` when a client disconnects.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    burst: f64,
+    per_second: f64,
+    over_budget: OverBudget,
+    buckets: HashMap<Identity, Bucket>,
+}
+
+// Remaining calls of one identity at the time of its last call.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Timestamp,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing bursts of `burst` calls, refilled at `per_second`
+    /// calls per second. Calls over budget are dropped.
+    pub fn new(burst: u32, per_second: u32) -> Self {
+        Self {
+            burst: f64::from(burst.max(1)),
+            per_second: f64::from(per_second),
+            over_budget: OverBudget::Drop,
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Sets what happens to calls over budget.
+    pub fn with_over_budget(mut self, over_budget: OverBudget) -> Self {
+        self.over_budget = over_budget;
+        self
+    }
+
+    /// Spends one call of `identity`'s budget at time `now` (the reducer's `ctx.timestamp`).
+    ///
+    /// Returns `Ok(true)` if the call may proceed. Over budget, returns `Ok(false)` under
+    /// `OverBudget::Drop` and an error under `OverBudget::Reject`.
+    pub fn admit(&mut self, identity: Identity, now: Timestamp) -> Result<bool, String> {
+        let bucket = self.buckets.entry(identity).or_insert(Bucket {
+            tokens: self.burst,
+            updated_at: now,
+        });
+        let elapsed_us = now.to_micros_since_unix_epoch() - bucket.updated_at.to_micros_since_unix_epoch();
+        let refill = elapsed_us.max(0) as f64 / 1_000_000.0 * self.per_second;
+        bucket.tokens = (bucket.tokens + refill).min(self.burst);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(true);
+        }
+        log::trace!("Identity {:?} is over its call budget.", identity);
+        match self.over_budget {
+            OverBudget::Drop => Ok(false),
+            OverBudget::Reject => Err("Rate limit exceeded; slow down".to_string()),
+        }
+    }
+
+    /// Drops the budget kept for `identity`, e.g. when it disconnects.
+    pub fn forget(&mut self, identity: Identity) {
+        self.buckets.remove(&identity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> Timestamp {
+        Timestamp::from_micros_since_unix_epoch(seconds * 1_000_000)
+    }
+
+    #[test]
+    fn admits_bursts_then_drops() {
+        let mut limiter = RateLimiter::new(2, 1);
+        assert_eq!(limiter.admit(Identity::ZERO, at(0)), Ok(true));
+        assert_eq!(limiter.admit(Identity::ZERO, at(0)), Ok(true));
+        assert_eq!(limiter.admit(Identity::ZERO, at(0)), Ok(false));
+    }
+
+    #[test]
+    fn refills_over_time_up_to_the_burst() {
+        let mut limiter = RateLimiter::new(2, 1);
+        for _ in 0..2 {
+            limiter.admit(Identity::ZERO, at(0)).unwrap();
+        }
+        assert_eq!(limiter.admit(Identity::ZERO, at(1)), Ok(true));
+        assert_eq!(limiter.admit(Identity::ZERO, at(1)), Ok(false));
+
+        // A long pause only refills `burst` calls.
+        for _ in 0..2 {
+            assert_eq!(limiter.admit(Identity::ZERO, at(100)), Ok(true));
+        }
+        assert_eq!(limiter.admit(Identity::ZERO, at(100)), Ok(false));
+    }
+
+    #[test]
+    fn budgets_are_per_identity() {
+        let other = Identity::from_byte_array([1; 32]);
+        let mut limiter = RateLimiter::new(1, 1);
+        assert_eq!(limiter.admit(Identity::ZERO, at(0)), Ok(true));
+        assert_eq!(limiter.admit(other, at(0)), Ok(true));
+        assert_eq!(limiter.admit(Identity::ZERO, at(0)), Ok(false));
+
+        limiter.forget(Identity::ZERO);
+        assert_eq!(limiter.admit(Identity::ZERO, at(0)), Ok(true));
+    }
+
+    #[test]
+    fn reject_fails_calls_over_budget() {
+        let mut limiter = RateLimiter::new(1, 1).with_over_budget(OverBudget::Reject);
+        assert_eq!(limiter.admit(Identity::ZERO, at(0)), Ok(true));
+        assert!(limiter.admit(Identity::ZERO, at(0)).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+// External Crate Imports
+use bevy::prelude::*;
+use spacetimedb::ReducerContext;
+
+// Crate Imports
+use crate::{
+    install_room_app, is_room_app_installed, CurrentRoom, RoomId, SpacetimeId, StdbEntityMap,
+    DEFAULT_ROOM,
+};
+
+// --- Public API: Types ---
+
+/// Type-erased loader that spawns Bevy entities from the rows of one table that belong
+/// to the given room. Returns the number of spawned entities.
+pub type RowLoader =
+    Box<dyn Fn(&ReducerContext, RoomId, &mut World) -> Result<usize, String> + Send + Sync>;
+
+/// Bevy `Resource` holding the row loaders registered with `StdbAppExt::add_row_loader`,
+/// together with the name of the table each one reads.
+#[derive(Resource, Default)]
+pub struct RowLoaders(pub Vec<(&'static str, RowLoader)>);
+
+// --- Public API: Functions ---
+
+/// Registers a loader spawning a `B` bundle per row of `table`; see `StdbAppExt::add_row_loader`.
+pub(crate) fn register_row_loader<B: Bundle>(
+    app: &mut App,
+    table: &'static str,
+    load: fn(&ReducerContext, RoomId) -> Vec<(u32, B)>,
+) {
+    let loader: RowLoader = Box::new(move |ctx, room, world| {
+        let rows = load(ctx, room);
+        let count = rows.len();
+        for (id, bundle) in rows {
+            let bevy_entity = world.spawn((bundle, SpacetimeId(id))).id();
+            world.resource_mut::<StdbEntityMap>().insert(bevy_entity, id);
+        }
+        Ok(count)
+    });
+    app.init_resource::<RowLoaders>();
+    app.world_mut().resource_mut::<RowLoaders>().0.push((table, loader));
+}
+
+/// Rebuilds the Bevy world of `app` from the database by running every registered row loader
+/// for the app's room (its `CurrentRoom`, or the `DEFAULT_ROOM`).
+/// Returns the total number of spawned entities.
+pub fn rehydrate_world(app: &mut App, ctx: &ReducerContext) -> Result<usize, String> {
+    let world = app.world_mut();
+    let room = world.get_resource::<CurrentRoom>().map_or(DEFAULT_ROOM, |current| current.0);
+    let Some(loaders) = world.remove_resource::<RowLoaders>() else {
+        return Ok(0); // Nothing registered.
+    };
+
+    let mut result = Ok(0);
+    for (table, loader) in &loaders.0 {
+        match loader(ctx, room, world) {
+            Ok(count) => {
+                log::info!("Rehydrated {} Bevy entities of room {} from table '{}'.", count, room, table);
+                result = result.map(|total| total + count);
+            }
+            Err(e) => {
+                log::error!("Failed to rehydrate Bevy entities from table '{}': {}", table, e);
+                result = Err(e);
+                break;
+            }
+        }
+    }
+    world.insert_resource(loaders);
+    result
+}
+
+/// Ensures a Bevy `App` is installed, rebuilding it from the database if it is missing.
+///
+/// The app only lives in module memory, so it is lost when the module restarts or is
+/// republished without clearing data. In that case the next reducer builds a fresh app
+/// with `build_app`, rehydrates it from the tables and installs it.
+pub fn rehydrate_app_if_missing(
+    ctx: &ReducerContext,
+    build_app: impl FnOnce(&ReducerContext, RoomId) -> App,
+) -> Result<(), String> {
+    rehydrate_room_if_missing(DEFAULT_ROOM, ctx, build_app)
+}
+
+/// Ensures `room` has an installed Bevy `App`, like `rehydrate_app_if_missing`.
+/// `build_app` receives the room, and row loaders only spawn the room's rows.
+pub fn rehydrate_room_if_missing(
+    room: RoomId,
+    ctx: &ReducerContext,
+    build_app: impl FnOnce(&ReducerContext, RoomId) -> App,
+) -> Result<(), String> {
+    if is_room_app_installed(room)? {
+        return Ok(());
+    }
+    log::warn!("No Bevy App installed for room {}; rebuilding it from SpacetimeDB tables...", room);
+    let mut app = build_app(ctx, room);
+    app.insert_resource(CurrentRoom(room));
+    let count = rehydrate_world(&mut app, ctx)?;
+    install_room_app(room, app)?;
+    log::info!("Bevy App of room {} rehydrated with {} entities.", room, count);
+    Ok(())
+}
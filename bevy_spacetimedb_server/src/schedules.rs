@@ -0,0 +1,103 @@
+// External Crate Imports
+use bevy::app::Main;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+use spacetimedb::ReducerContext;
+
+// Crate Imports
+use crate::{run_room_now, RoomId, DEFAULT_ROOM};
+
+// --- Public API: Types ---
+
+/// A schedule run by `run_bevy_update` every `every_n_ticks` ticks.
+#[derive(Debug, Clone)]
+pub struct TickSchedule {
+    /// The schedule to run.
+    pub label: InternedScheduleLabel,
+    /// Run period in ticks; `1` runs the schedule every tick.
+    pub every_n_ticks: u32,
+}
+
+/// Bevy `Resource` listing the schedules run by `run_bevy_update`, in order.
+/// Defaults to running `Main` (and therefore `Update`, `FixedUpdate`, ...) every tick.
+#[derive(Resource, Debug, Clone)]
+pub struct TickSchedules {
+    /// The schedules to run, in order.
+    pub schedules: Vec<TickSchedule>,
+    /// Number of ticks run so far.
+    pub tick: u64,
+}
+
+impl Default for TickSchedules {
+    fn default() -> Self {
+        Self {
+            schedules: vec![TickSchedule { label: Main.intern(), every_n_ticks: 1 }],
+            tick: 0,
+        }
+    }
+}
+
+// --- Public API: Functions ---
+
+/// Runs the schedule `label` of the `DEFAULT_ROOM` immediately from a reducer, via `run_bevy_now`.
+/// Database commands queued by the schedule's systems are applied in the reducer's transaction.
+pub fn run_bevy_schedule_now(
+    ctx: &ReducerContext,
+    reducer: &'static str,
+    label: impl ScheduleLabel,
+) -> Result<(), String> {
+    run_room_schedule_now(DEFAULT_ROOM, ctx, reducer, label)
+}
+
+/// Runs the schedule `label` of the world of `room` immediately, like `run_bevy_schedule_now`.
+pub fn run_room_schedule_now(
+    room: RoomId,
+    ctx: &ReducerContext,
+    reducer: &'static str,
+    label: impl ScheduleLabel,
+) -> Result<(), String> {
+    let label = label.intern();
+    run_room_now(room, ctx, reducer, |world| {
+        world
+            .try_run_schedule(label)
+            .map_err(|e| format!("Failed to run schedule {:?}: {}", label, e))
+    })
+}
+
+// --- Internal Functions ---
+
+/// Appends `label` to the `TickSchedules`, creating the schedule if needed.
+pub(crate) fn register_tick_schedule(app: &mut App, label: InternedScheduleLabel, every_n_ticks: u32) {
+    app.init_schedule(label);
+    app.init_resource::<TickSchedules>();
+    app.world_mut()
+        .resource_mut::<TickSchedules>()
+        .schedules
+        .push(TickSchedule { label, every_n_ticks: every_n_ticks.max(1) });
+}
+
+/// Runs the `TickSchedules` due this tick, then clears change trackers like `App::update`.
+/// Falls back to running `Main` when no `TickSchedules` resource exists.
+pub(crate) fn run_tick_schedules(world: &mut World) {
+    let Some(mut tick_schedules) = world.get_resource_mut::<TickSchedules>() else {
+        world.run_schedule(Main);
+        world.clear_trackers();
+        return;
+    };
+    let tick = tick_schedules.tick;
+    tick_schedules.tick += 1;
+    let due: Vec<InternedScheduleLabel> = tick_schedules
+        .schedules
+        .iter()
+        .filter(|schedule| tick % u64::from(schedule.every_n_ticks) == 0)
+        .map(|schedule| schedule.label)
+        .collect();
+
+    for label in due {
+        log::trace!("Running tick schedule {:?}.", label);
+        if let Err(e) = world.try_run_schedule(label) {
+            log::error!("Failed to run tick schedule {:?}: {}", label, e);
+        }
+    }
+    world.clear_trackers();
+}
@@ -0,0 +1,92 @@
+// Standard Library Imports
+use std::time::Duration;
+
+// External Crate Imports
+use bevy::app::{FixedMain, RunFixedMainLoop, RunFixedMainLoopSystem};
+use bevy::prelude::*;
+use spacetimedb::Timestamp;
+
+// --- Public API: Types ---
+
+/// Bevy `Resource` tracking the SpacetimeDB timestamps that drive Bevy's clocks.
+///
+/// `TimePlugin` reads the system clock, which is not available inside a module, so it must
+/// stay disabled. Instead, `run_bevy_update` advances `Time<Virtual>` from the reducer's
+/// timestamp, and `run_fixed_main_loop_system` steps `Time<Fixed>` and `FixedMain`.
+#[derive(Resource, Debug, Default)]
+pub struct StdbClock {
+    /// Timestamp of the previous tick, `None` before the first one.
+    pub last_tick: Option<Timestamp>,
+    /// Virtual time accumulated but not yet consumed by fixed steps.
+    pub fixed_overstep: Duration,
+}
+
+// --- Bevy Plugin ---
+
+/// Bevy `Plugin` driving `Time`, `Time<Virtual>` and `Time<Fixed>` from SpacetimeDB ticks.
+/// Added by `SpacetimeDbServerPlugin`.
+pub(crate) struct StdbTimePlugin;
+
+impl Plugin for StdbTimePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StdbClock>()
+           .init_resource::<Time>()
+           .init_resource::<Time<Virtual>>()
+           .init_resource::<Time<Fixed>>()
+           .add_systems(
+               RunFixedMainLoop,
+               run_fixed_main_loop_system.in_set(RunFixedMainLoopSystem::FixedMainLoop),
+           );
+    }
+}
+
+// --- Bevy Systems ---
+
+/// Exclusive system that runs `FixedMain` once per elapsed `Time<Fixed>` timestep,
+/// catching up when a tick arrives late. `Time` reflects `Time<Fixed>` during each step.
+pub fn run_fixed_main_loop_system(world: &mut World) {
+    let delta = world.resource::<Time<Virtual>>().delta();
+    let timestep = world.resource::<Time<Fixed>>().timestep();
+    world.resource_mut::<StdbClock>().fixed_overstep += delta;
+
+    while world.resource::<StdbClock>().fixed_overstep >= timestep {
+        world.resource_mut::<StdbClock>().fixed_overstep -= timestep;
+        world.resource_mut::<Time<Fixed>>().advance_by(timestep);
+        *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
+        if world.try_run_schedule(FixedMain).is_err() {
+            break; // No fixed schedule registered.
+        }
+    }
+
+    *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
+}
+
+// --- Internal Functions ---
+
+/// Advances `Time<Virtual>` and `Time` to the tick at `now`.
+/// The first tick has a zero delta; pausing, relative speed and `max_delta` are honored.
+pub(crate) fn advance_time(world: &mut World, now: Timestamp) {
+    let Some(mut clock) = world.get_resource_mut::<StdbClock>() else {
+        return; // Time is not driven by this integration.
+    };
+    let raw_delta = match clock.last_tick.replace(now) {
+        Some(last) => {
+            let micros = now.to_micros_since_unix_epoch() - last.to_micros_since_unix_epoch();
+            Duration::from_micros(micros.max(0) as u64)
+        }
+        None => Duration::ZERO,
+    };
+
+    let mut virtual_time = world.resource_mut::<Time<Virtual>>();
+    let delta = if virtual_time.is_paused() {
+        Duration::ZERO
+    } else {
+        raw_delta
+            .min(virtual_time.max_delta())
+            .mul_f64(virtual_time.relative_speed_f64())
+    };
+    virtual_time.advance_by(delta);
+    let generic = virtual_time.as_generic();
+    *world.resource_mut::<Time>() = generic;
+    log::trace!("Advanced Bevy time by {:?} (raw {:?}).", delta, raw_delta);
+}
@@ -6,7 +6,10 @@ use bevy_spacetimedb::{
     StdbConnectionErrorEvent, StdbDisconnectedEvent, StdbPlugin, UpdateEvent,
 };
 use spacetimedb_sdk::{ReducerEvent, Table};
-use stdb::{DbConnection, Entity, EntityTableAccess, Reducer, enter_game};
+use stdb::{
+    DbConnection, Entity, EntityTableAccess, InputAck, InputAckTableAccess, PlayerTableAccess,
+    Reducer, TickStats, TickStatsTableAccess, enter_game,
+};
 
 use crate::stdb::receive_player_input;
 
@@ -70,7 +73,11 @@ pub fn main() {
                     plugin
                         .on_insert(app, db.entity())
                         .on_update(app, db.entity())
-                        .on_delete(app, db.entity());
+                        .on_delete(app, db.entity())
+                        .on_insert(app, db.tick_stats())
+                        .on_update(app, db.tick_stats())
+                        .on_insert(app, db.input_ack())
+                        .on_update(app, db.input_ack());
 
                     let enter_game = plugin.reducer_event::<EnterGameEvent>(app);
                     reducers.on_enter_game(move |ctx, _name| {
@@ -91,6 +98,8 @@ pub fn main() {
                 on_player_inserted,
                 on_player_updated,
                 on_player_deleted,
+                on_tick_stats_updated,
+                on_input_acked,
                 on_keyboard_input,
             ),
         )
@@ -142,18 +151,33 @@ fn on_connected(
         info!("Connected to SpacetimeDB");
 
         // Call any reducers
-        stdb.reducers().enter_game("doodoo".to_owned()).unwrap();
+        stdb.reducers().enter_game(player_name(&stdb)).unwrap();
         // Subscribe to any tables
         stdb.subscribe()
-            .on_applied(|_| info!("Subscription to players applied"))
-            .on_error(|_, err| error!("Subscription to players failed for: {}", err))
-            .subscribe("SELECT * FROM entity");
+            .on_applied(|_| info!("Subscription to game tables applied"))
+            .on_error(|_, err| error!("Subscription to game tables failed for: {}", err))
+            .subscribe([
+                "SELECT * FROM entity",
+                "SELECT * FROM tick_stats",
+                "SELECT * FROM input_ack",
+                "SELECT * FROM player",
+                "SELECT * FROM connection",
+            ]);
 
         // Access your database cache (since it's not yet populated here this line might return 0)
         info!("Entity count: {}", stdb.db().entity().count());
     }
 }
 
+/// Name to enter the game with: the first command line argument, or one derived from the
+/// connection's identity, so that every client gets a name of its own.
+fn player_name(stdb: &StdbConnection<DbConnection>) -> String {
+    std::env::args().nth(1).unwrap_or_else(|| {
+        let identity = stdb.identity().to_string();
+        format!("player-{}", &identity[..8])
+    })
+}
+
 fn on_register_player(mut events: EventReader<ReducerResultEvent<EnterGameEvent>>) {
     for event in events.read() {
         info!("Entered game: {:?}", event);
@@ -167,7 +191,7 @@ pub struct SpacetimeDbEntity {
 }
 
 fn on_player_inserted(mut events: EventReader<InsertEvent<Entity>>, mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,) {
+    mut materials: ResMut<Assets<StandardMaterial>>, stdb: Res<StdbConnection<DbConnection>>,) {
     for event in events.read() {
         commands.spawn((SpacetimeDbEntity {
             entity_id: event.row.entity_id,
@@ -180,7 +204,8 @@ fn on_player_inserted(mut events: EventReader<InsertEvent<Entity>>, mut commands
             id: event.row.entity_id,
         }
         ));
-        info!("Player inserted: {:?}", event.row);
+        let owner = event.row.owner_identity.and_then(|owner| stdb.db().player().identity().find(&owner));
+        info!("Player inserted: {:?} ({:?})", event.row, owner.map(|player| player.name));
     }
 }
 
@@ -206,12 +231,40 @@ fn on_player_deleted(mut events: EventReader<DeleteEvent<Entity>>) {
     }
 }
 
-fn on_keyboard_input(keyboard_input: Res<ButtonInput<KeyCode>>, stdb: Res<StdbConnection<DbConnection>>,) {
+fn on_tick_stats_updated(
+    mut inserted: EventReader<InsertEvent<TickStats>>,
+    mut updated: EventReader<UpdateEvent<TickStats>>,
+) {
+    let stats = inserted.read().map(|event| &event.row);
+    for stats in stats.chain(updated.read().map(|event| &event.new)) {
+        if stats.last_tick_late {
+            warn!(
+                "Room {} tick was late: {} us after the previous one (scheduled every {} us)",
+                stats.room_id, stats.last_interval_us, stats.scheduled_interval_us
+            );
+        }
+    }
+}
+
+fn on_input_acked(
+    mut inserted: EventReader<InsertEvent<InputAck>>,
+    mut updated: EventReader<UpdateEvent<InputAck>>,
+) {
+    let acks = inserted.read().map(|event| &event.row);
+    for ack in acks.chain(updated.read().map(|event| &event.new)) {
+        // Inputs up to this sequence number are applied; later ones are still pending.
+        debug!("Entity {} acknowledged input {}", ack.entity_id, ack.last_processed_input);
+    }
+}
+
+fn on_keyboard_input(keyboard_input: Res<ButtonInput<KeyCode>>, stdb: Res<StdbConnection<DbConnection>>, mut input_seq: Local<u32>) {
     let mut x = 0.0;
     let mut y = 0.0;
     if keyboard_input.pressed(KeyCode::ArrowLeft) { x -= 0.1; }
     if keyboard_input.pressed(KeyCode::ArrowRight) { x += 0.1; }
     if keyboard_input.pressed(KeyCode::ArrowUp) { y += 0.1; }
     if keyboard_input.pressed(KeyCode::ArrowDown) { y -= 0.1; }
-    stdb.reducers().receive_player_input(x,y).unwrap();
+    // Number inputs so the server can acknowledge the last one it applied.
+    *input_seq += 1;
+    stdb.reducers().receive_player_input(x, y, *input_seq).unwrap();
 }
\ No newline at end of file
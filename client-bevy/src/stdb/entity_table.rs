@@ -2,6 +2,7 @@
 // WILL NOT BE SAVED. MODIFY TABLES IN YOUR MODULE SOURCE CODE INSTEAD.
 
 #![allow(unused, clippy::all)]
+use super::entity_kind_type::EntityKind;
 use super::entity_type::Entity;
 use super::vec_2_type::Vec2;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
@@ -4,6 +4,7 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::entity_kind_type::EntityKind;
 use super::vec_2_type::Vec2;
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
@@ -11,7 +12,9 @@ use super::vec_2_type::Vec2;
 pub struct Entity {
     pub entity_id: u32,
     pub position: Vec2,
-    pub owner_identity: __sdk::Identity,
+    pub owner_identity: Option<__sdk::Identity>,
+    pub room_id: u32,
+    pub kind: EntityKind,
 }
 
 impl __sdk::InModule for Entity {
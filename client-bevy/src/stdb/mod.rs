@@ -5,27 +5,71 @@
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 pub mod connect_reducer;
+pub mod connection_table;
+pub mod connection_type;
+pub mod create_room_reducer;
 pub mod disconnect_reducer;
 pub mod enter_game_reducer;
+pub mod enter_room_reducer;
+pub mod entity_kind_type;
 pub mod entity_table;
 pub mod entity_type;
+pub mod input_ack_table;
+pub mod input_ack_type;
+pub mod player_table;
+pub mod player_type;
 pub mod process_tick_reducer;
+pub mod prune_connections_reducer;
 pub mod receive_player_input_reducer;
+pub mod room_table;
+pub mod room_type;
 pub mod scheduled_tick_table;
 pub mod scheduled_tick_type;
+pub mod server_config_table;
+pub mod server_config_type;
+pub mod set_disconnect_grace_reducer;
+pub mod set_tick_rate_reducer;
+pub mod spawn_entity_reducer;
+pub mod tick_stats_table;
+pub mod tick_stats_type;
 pub mod vec_2_type;
 
 pub use connect_reducer::{connect, set_flags_for_connect, ConnectCallbackId};
+pub use connection_table::*;
+pub use connection_type::Connection;
+pub use create_room_reducer::{create_room, set_flags_for_create_room, CreateRoomCallbackId};
 pub use disconnect_reducer::{disconnect, set_flags_for_disconnect, DisconnectCallbackId};
 pub use enter_game_reducer::{enter_game, set_flags_for_enter_game, EnterGameCallbackId};
+pub use enter_room_reducer::{enter_room, set_flags_for_enter_room, EnterRoomCallbackId};
+pub use entity_kind_type::EntityKind;
 pub use entity_table::*;
 pub use entity_type::Entity;
+pub use input_ack_table::*;
+pub use input_ack_type::InputAck;
+pub use player_table::*;
+pub use player_type::Player;
 pub use process_tick_reducer::{process_tick, set_flags_for_process_tick, ProcessTickCallbackId};
+pub use prune_connections_reducer::{
+    prune_connections, set_flags_for_prune_connections, PruneConnectionsCallbackId,
+};
 pub use receive_player_input_reducer::{
     receive_player_input, set_flags_for_receive_player_input, ReceivePlayerInputCallbackId,
 };
+pub use room_table::*;
+pub use room_type::Room;
 pub use scheduled_tick_table::*;
 pub use scheduled_tick_type::ScheduledTick;
+pub use server_config_table::*;
+pub use server_config_type::ServerConfig;
+pub use set_disconnect_grace_reducer::{
+    set_disconnect_grace, set_flags_for_set_disconnect_grace, SetDisconnectGraceCallbackId,
+};
+pub use set_tick_rate_reducer::{
+    set_flags_for_set_tick_rate, set_tick_rate, SetTickRateCallbackId,
+};
+pub use spawn_entity_reducer::{set_flags_for_spawn_entity, spawn_entity, SpawnEntityCallbackId};
+pub use tick_stats_table::*;
+pub use tick_stats_type::TickStats;
 pub use vec_2_type::Vec2;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -37,10 +81,40 @@ pub use vec_2_type::Vec2;
 
 pub enum Reducer {
     Connect,
+    CreateRoom {
+        room_id: u32,
+    },
     Disconnect,
-    EnterGame { name: String },
-    ProcessTick { tick: ScheduledTick },
-    ReceivePlayerInput { x: f32, y: f32 },
+    EnterGame {
+        name: String,
+    },
+    EnterRoom {
+        name: String,
+        room_id: u32,
+    },
+    ProcessTick {
+        tick: ScheduledTick,
+    },
+    PruneConnections {
+        max_idle_ms: u64,
+    },
+    ReceivePlayerInput {
+        x: f32,
+        y: f32,
+        seq: u32,
+    },
+    SetDisconnectGrace {
+        grace_ms: u64,
+    },
+    SetTickRate {
+        tick_interval_ms: u64,
+    },
+    SpawnEntity {
+        room_id: u32,
+        kind: EntityKind,
+        x: f32,
+        y: f32,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -51,10 +125,16 @@ impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
             Reducer::Connect => "connect",
+            Reducer::CreateRoom { .. } => "create_room",
             Reducer::Disconnect => "disconnect",
             Reducer::EnterGame { .. } => "enter_game",
+            Reducer::EnterRoom { .. } => "enter_room",
             Reducer::ProcessTick { .. } => "process_tick",
+            Reducer::PruneConnections { .. } => "prune_connections",
             Reducer::ReceivePlayerInput { .. } => "receive_player_input",
+            Reducer::SetDisconnectGrace { .. } => "set_disconnect_grace",
+            Reducer::SetTickRate { .. } => "set_tick_rate",
+            Reducer::SpawnEntity { .. } => "spawn_entity",
         }
     }
 }
@@ -67,6 +147,13 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 &value.args,
             )?
             .into()),
+            "create_room" => Ok(
+                __sdk::parse_reducer_args::<create_room_reducer::CreateRoomArgs>(
+                    "create_room",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "disconnect" => Ok(
                 __sdk::parse_reducer_args::<disconnect_reducer::DisconnectArgs>(
                     "disconnect",
@@ -81,6 +168,13 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "enter_room" => Ok(
+                __sdk::parse_reducer_args::<enter_room_reducer::EnterRoomArgs>(
+                    "enter_room",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "process_tick" => Ok(
                 __sdk::parse_reducer_args::<process_tick_reducer::ProcessTickArgs>(
                     "process_tick",
@@ -88,10 +182,29 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "prune_connections" => Ok(__sdk::parse_reducer_args::<
+                prune_connections_reducer::PruneConnectionsArgs,
+            >("prune_connections", &value.args)?
+            .into()),
             "receive_player_input" => Ok(__sdk::parse_reducer_args::<
                 receive_player_input_reducer::ReceivePlayerInputArgs,
             >("receive_player_input", &value.args)?
             .into()),
+            "set_disconnect_grace" => Ok(__sdk::parse_reducer_args::<
+                set_disconnect_grace_reducer::SetDisconnectGraceArgs,
+            >("set_disconnect_grace", &value.args)?
+            .into()),
+            "set_tick_rate" => Ok(__sdk::parse_reducer_args::<
+                set_tick_rate_reducer::SetTickRateArgs,
+            >("set_tick_rate", &value.args)?
+            .into()),
+            "spawn_entity" => Ok(
+                __sdk::parse_reducer_args::<spawn_entity_reducer::SpawnEntityArgs>(
+                    "spawn_entity",
+                    &value.args,
+                )?
+                .into(),
+            ),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -106,8 +219,14 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    connection: __sdk::TableUpdate<Connection>,
     entity: __sdk::TableUpdate<Entity>,
+    input_ack: __sdk::TableUpdate<InputAck>,
+    player: __sdk::TableUpdate<Player>,
+    room: __sdk::TableUpdate<Room>,
     scheduled_tick: __sdk::TableUpdate<ScheduledTick>,
+    server_config: __sdk::TableUpdate<ServerConfig>,
+    tick_stats: __sdk::TableUpdate<TickStats>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -116,11 +235,25 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "connection" => {
+                    db_update.connection = connection_table::parse_table_update(table_update)?
+                }
                 "entity" => db_update.entity = entity_table::parse_table_update(table_update)?,
+                "input_ack" => {
+                    db_update.input_ack = input_ack_table::parse_table_update(table_update)?
+                }
+                "player" => db_update.player = player_table::parse_table_update(table_update)?,
+                "room" => db_update.room = room_table::parse_table_update(table_update)?,
                 "scheduled_tick" => {
                     db_update.scheduled_tick =
                         scheduled_tick_table::parse_table_update(table_update)?
                 }
+                "server_config" => {
+                    db_update.server_config = server_config_table::parse_table_update(table_update)?
+                }
+                "tick_stats" => {
+                    db_update.tick_stats = tick_stats_table::parse_table_update(table_update)?
+                }
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -147,12 +280,30 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.connection = cache
+            .apply_diff_to_table::<Connection>("connection", &self.connection)
+            .with_updates_by_pk(|row| &row.connection_id);
         diff.entity = cache
             .apply_diff_to_table::<Entity>("entity", &self.entity)
             .with_updates_by_pk(|row| &row.entity_id);
+        diff.input_ack = cache
+            .apply_diff_to_table::<InputAck>("input_ack", &self.input_ack)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.player = cache
+            .apply_diff_to_table::<Player>("player", &self.player)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.room = cache
+            .apply_diff_to_table::<Room>("room", &self.room)
+            .with_updates_by_pk(|row| &row.room_id);
         diff.scheduled_tick = cache
             .apply_diff_to_table::<ScheduledTick>("scheduled_tick", &self.scheduled_tick)
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.server_config = cache
+            .apply_diff_to_table::<ServerConfig>("server_config", &self.server_config)
+            .with_updates_by_pk(|row| &row.id);
+        diff.tick_stats = cache
+            .apply_diff_to_table::<TickStats>("tick_stats", &self.tick_stats)
+            .with_updates_by_pk(|row| &row.room_id);
 
         diff
     }
@@ -162,8 +313,14 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    connection: __sdk::TableAppliedDiff<'r, Connection>,
     entity: __sdk::TableAppliedDiff<'r, Entity>,
+    input_ack: __sdk::TableAppliedDiff<'r, InputAck>,
+    player: __sdk::TableAppliedDiff<'r, Player>,
+    room: __sdk::TableAppliedDiff<'r, Room>,
     scheduled_tick: __sdk::TableAppliedDiff<'r, ScheduledTick>,
+    server_config: __sdk::TableAppliedDiff<'r, ServerConfig>,
+    tick_stats: __sdk::TableAppliedDiff<'r, TickStats>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -176,12 +333,22 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Connection>("connection", &self.connection, event);
         callbacks.invoke_table_row_callbacks::<Entity>("entity", &self.entity, event);
+        callbacks.invoke_table_row_callbacks::<InputAck>("input_ack", &self.input_ack, event);
+        callbacks.invoke_table_row_callbacks::<Player>("player", &self.player, event);
+        callbacks.invoke_table_row_callbacks::<Room>("room", &self.room, event);
         callbacks.invoke_table_row_callbacks::<ScheduledTick>(
             "scheduled_tick",
             &self.scheduled_tick,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<ServerConfig>(
+            "server_config",
+            &self.server_config,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TickStats>("tick_stats", &self.tick_stats, event);
     }
 }
 
@@ -757,7 +924,13 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        connection_table::register_table(client_cache);
         entity_table::register_table(client_cache);
+        input_ack_table::register_table(client_cache);
+        player_table::register_table(client_cache);
+        room_table::register_table(client_cache);
         scheduled_tick_table::register_table(client_cache);
+        server_config_table::register_table(client_cache);
+        tick_stats_table::register_table(client_cache);
     }
 }
@@ -9,6 +9,7 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 pub(super) struct ReceivePlayerInputArgs {
     pub x: f32,
     pub y: f32,
+    pub seq: u32,
 }
 
 impl From<ReceivePlayerInputArgs> for super::Reducer {
@@ -16,6 +17,7 @@ impl From<ReceivePlayerInputArgs> for super::Reducer {
         Self::ReceivePlayerInput {
             x: args.x,
             y: args.y,
+            seq: args.seq,
         }
     }
 }
@@ -36,7 +38,7 @@ pub trait receive_player_input {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_receive_player_input`] callbacks.
-    fn receive_player_input(&self, x: f32, y: f32) -> __sdk::Result<()>;
+    fn receive_player_input(&self, x: f32, y: f32, seq: u32) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `receive_player_input`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -46,7 +48,7 @@ pub trait receive_player_input {
     /// to cancel the callback.
     fn on_receive_player_input(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &f32, &f32) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &u32) + Send + 'static,
     ) -> ReceivePlayerInputCallbackId;
     /// Cancel a callback previously registered by [`Self::on_receive_player_input`],
     /// causing it not to run in the future.
@@ -54,13 +56,13 @@ pub trait receive_player_input {
 }
 
 impl receive_player_input for super::RemoteReducers {
-    fn receive_player_input(&self, x: f32, y: f32) -> __sdk::Result<()> {
+    fn receive_player_input(&self, x: f32, y: f32, seq: u32) -> __sdk::Result<()> {
         self.imp
-            .call_reducer("receive_player_input", ReceivePlayerInputArgs { x, y })
+            .call_reducer("receive_player_input", ReceivePlayerInputArgs { x, y, seq })
     }
     fn on_receive_player_input(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &f32, &f32) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &f32, &f32, &u32) + Send + 'static,
     ) -> ReceivePlayerInputCallbackId {
         ReceivePlayerInputCallbackId(self.imp.on_reducer(
             "receive_player_input",
@@ -68,7 +70,7 @@ impl receive_player_input for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::ReceivePlayerInput { x, y },
+                            reducer: super::Reducer::ReceivePlayerInput { x, y, seq },
                             ..
                         },
                     ..
@@ -76,7 +78,7 @@ impl receive_player_input for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, x, y)
+                callback(ctx, x, y, seq)
             }),
         ))
     }
@@ -2,36 +2,106 @@
 // pub mod bevy_logic; // Keep if used elsewhere, otherwise remove if logic is inlined
 
 // Standard Library Imports
-use std::cell::UnsafeCell;
+use std::collections::hash_map::Entry as MapEntry;
+use std::collections::{HashMap, VecDeque};
 use std::time::Duration;
 
 // External Crate Imports
 use bevy::app::ScheduleRunnerPlugin;
+use bevy::ecs::component::HookContext;
+use bevy::ecs::world::DeferredWorld;
 use bevy::ecs::event::EventReader;
 use bevy::prelude::*;
 use bevy::time::TimePlugin;
 use once_cell::sync::Lazy;
-use spacetimedb::{Identity, ReducerContext, ScheduleAt, SpacetimeType, Table};
+use spin::Mutex;
+use spacetimedb::{
+    ConnectionId, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp,
+};
 
 // Workspace Crate Imports (Integration Library)
 use bevy_spacetimedb_server::{
-    create_send_event_action, process_bevy_actions, process_bevy_commands, run_bevy_update,
-    schedule_bevy_action, CommandQueue, DbCommand, DbCommandClosure, SpacetimeDbServerPlugin,
+    borrow_room_app, create_resource_mut_action, create_send_reducer_event_action, next_row_id,
+    process_bevy_actions, process_bevy_commands, rehydrate_room_if_missing, reserve_row_ids,
+    run_bevy_update, run_room_now, schedule_room_action_from, CommandStats, CurrentRoom, DbBackend,
+    DbCommand, DbCommandFailed, DbCommandKind, DbErrorPolicy, FromReducer, MirrorPlugin,
+    RateLimiter, RoomId, SpacetimeDbServerPlugin, StdbCommands, StdbEntityMap, StdbMirror,
+    StdbReadRow, StdbRow, DEFAULT_ROOM,
+};
+
+// Crate Modules
+mod prefab;
+use prefab::{
+    NpcPrefab, PickupPrefab, PlayerPrefab, PrefabAppExt, PrefabRegistry, ProjectilePrefab,
 };
 
-// --- Global Static Bevy Application State ---
+// --- Constants ---
+
+/// Default interval between two `process_tick` calls, also used as Bevy's fixed timestep.
+/// Can be changed at runtime with the `set_tick_rate` reducer.
+const DEFAULT_TICK_INTERVAL_MS: u64 = 16; // Approx 60 FPS
+
+/// Allowed range for the tick interval, in milliseconds.
+const TICK_INTERVAL_RANGE_MS: std::ops::RangeInclusive<u64> = 1..=1000;
+
+/// A tick is flagged as late when the time since the previous tick exceeds the
+/// scheduled interval by more than this percentage.
+const LATE_TICK_TOLERANCE_PERCENT: u64 = 25;
+
+/// Weight of the newest sample in the rolling tick interval average, as `1 / N`.
+const TICK_STATS_SMOOTHING: u64 = 8;
+
+/// Number of ticks between two writes of a room's `TickStats` row. Late ticks are
+/// published right away.
+const TICK_STATS_PUBLISH_TICKS: u64 = 60;
+
+/// Default time a disconnected player's entity is kept, so the player can resume it.
+/// Can be changed at runtime with the `set_disconnect_grace` reducer.
+const DEFAULT_DISCONNECT_GRACE_MS: u64 = 30_000;
+
+/// Allowed range for the disconnect grace period, in milliseconds.
+const DISCONNECT_GRACE_RANGE_MS: std::ops::RangeInclusive<u64> = 0..=3_600_000;
+
+/// Allowed length of player names, in characters.
+const PLAYER_NAME_LENGTH: std::ops::RangeInclusive<usize> = 3..=24;
+
+/// Minimum time between two `last_activity` updates of a connection, in microseconds,
+/// so frequent reducer calls don't rewrite the row every time.
+const ACTIVITY_RESOLUTION_US: i64 = 5_000_000;
+
+/// Movement speed in world units per second for an input direction of length 1.
+const PLAYER_SPEED: f32 = 60.0;
 
-// Container to hold the Bevy App instance within a static context.
-// Required because SpacetimeDB reducers (like `process_tick`) are called
-// outside the main Bevy execution context but need access to the App/World.
-// SAFETY: This relies on careful manual synchronization. Access is guarded by
-// assumptions that `init` runs once before any `process_tick`, and `process_tick`
-// calls are serialized by SpacetimeDB.
-struct UnsafeAppContainer(UnsafeCell<Option<App>>);
-unsafe impl Send for UnsafeAppContainer {}
-unsafe impl Sync for UnsafeAppContainer {}
+/// Maximum length of an input direction; longer directions are scaled down to it.
+const MAX_INPUT_MAGNITUDE: f32 = 1.0;
 
-static BEVY_APP: Lazy<UnsafeAppContainer> = Lazy::new(|| UnsafeAppContainer(UnsafeCell::new(None)));
+/// Number of `receive_player_input` calls a client may make at once.
+const INPUT_BURST: u32 = 30;
+
+/// Sustained number of `receive_player_input` calls per second a client may make.
+/// Calls over budget are dropped.
+const INPUT_RATE_PER_SECOND: u32 = 90;
+
+/// Maximum number of inputs buffered per player. One input is applied per fixed step, so
+/// this bounds the input delay to as many steps; older inputs are dropped.
+const MAX_BUFFERED_INPUTS: usize = 8;
+
+/// Precision of positions written to the `Entity` table, in world units.
+const POSITION_QUANTUM: f32 = 0.01;
+
+/// Minimum distance, in world units, a position must move before its row is updated.
+const POSITION_EPSILON: f32 = 0.05;
+
+// --- Module State ---
+
+/// Per-client budget of `receive_player_input` calls.
+static INPUT_LIMITER: Lazy<Mutex<RateLimiter>> =
+    Lazy::new(|| Mutex::new(RateLimiter::new(INPUT_BURST, INPUT_RATE_PER_SECOND)));
+
+/// Tick metrics of each room, aggregated every tick and published to `TickStats`
+/// every `TICK_STATS_PUBLISH_TICKS` ticks.
+static TICK_STATS: Lazy<Mutex<HashMap<RoomId, TickStats>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 
 // --- SpacetimeDB Type Definitions ---
 
@@ -41,50 +111,334 @@ pub struct Vec2 {
     pub y: f32,
 }
 
+/// What an `Entity` row represents. Each kind is spawned from its prefab in `prefab.rs`.
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EntityKind {
+    /// Controlled by its owning client.
+    Player,
+    /// Controlled by the server.
+    Npc,
+    /// Short-lived moving object, e.g. a shot.
+    Projectile,
+    /// Static item that can be picked up.
+    Pickup,
+}
+
 // --- SpacetimeDB Table Definitions ---
 
 /// SpacetimeDB table used solely to trigger the `process_tick` reducer at regular intervals.
+/// Each room has its own row, so each room's Bevy world is ticked independently.
 #[spacetimedb::table(name = scheduled_tick, scheduled(process_tick))]
 pub struct ScheduledTick {
     #[primary_key]
     #[auto_inc]
     pub scheduled_id: u64,
     pub scheduled_at: ScheduleAt,
+    /// The room whose Bevy world this row ticks.
+    pub room_id: u32,
+}
+
+/// A room (e.g. a match instance) with its own Bevy world, hosted by this module.
+/// Room `0` is created by `init`; more are created with the `create_room` reducer.
+#[spacetimedb::table(name = room, public)]
+#[derive(Debug, Clone)]
+pub struct Room {
+    #[primary_key]
+    pub room_id: u32,
+    /// When the room was created.
+    pub created_at: Timestamp,
+}
+
+/// Singleton table holding runtime server configuration. Private: only reducers read it.
+#[spacetimedb::table(name = server_config)]
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    #[primary_key]
+    /// Always `0`; there is a single configuration row.
+    pub id: u32,
+    /// Interval between two `process_tick` calls, in milliseconds.
+    pub tick_interval_ms: u64,
+    /// The `Identity` allowed to change the configuration (the module publisher).
+    pub admin_identity: Identity,
+    /// Next `Entity` ID to allocate. The allocator lives in module memory, so it is
+    /// persisted here to never hand out an ID of a deleted row again after a restart.
+    pub next_entity_id: u32,
+    /// Time a disconnected player's entity is kept before it is despawned, in milliseconds.
+    pub disconnect_grace_ms: u64,
+}
+
+/// Rolling tick health metrics of a room. Aggregated in module memory every `process_tick`
+/// and written every `TICK_STATS_PUBLISH_TICKS` ticks, or right away when a tick is late,
+/// so subscribers are not sent an update per tick. Public so clients and dashboards can
+/// subscribe to it.
+///
+/// Reducers can not read a clock while they run, so tick timing is measured between the
+/// timestamps of consecutive ticks: a tick that overruns the scheduled interval delays
+/// the next one, which is then flagged as late. The first tick after a module restart
+/// takes no interval sample, since the time spent offline says nothing about tick health.
+#[spacetimedb::table(name = tick_stats, public)]
+#[derive(Debug, Clone)]
+pub struct TickStats {
+    #[primary_key]
+    pub room_id: u32,
+    /// Number of ticks recorded.
+    pub tick_count: u64,
+    /// Timestamp of the latest tick.
+    pub last_tick_at: Timestamp,
+    /// Scheduled interval between two ticks, in microseconds.
+    pub scheduled_interval_us: u64,
+    /// Time between the previous tick and the latest one, in microseconds.
+    pub last_interval_us: u64,
+    /// Rolling average of the time between ticks, in microseconds.
+    pub avg_interval_us: u64,
+    /// Longest time between two ticks, in microseconds.
+    pub max_interval_us: u64,
+    /// Whether the latest tick started late.
+    pub last_tick_late: bool,
+    /// Number of ticks that started late.
+    pub late_ticks: u64,
+    /// Scheduled Bevy actions processed by the latest tick.
+    pub last_actions: u32,
+    /// Database commands executed by the latest tick.
+    pub last_commands: u32,
+    /// Database commands dropped by coalescing in the latest tick.
+    pub last_commands_coalesced: u32,
+    /// Errors (failed commands or Bevy update) in the latest tick.
+    pub last_errors: u32,
+    /// Scheduled Bevy actions processed since the room started.
+    pub total_actions: u64,
+    /// Database commands executed since the room started.
+    pub total_commands: u64,
+    /// Errors since the room started.
+    pub total_errors: u64,
+    /// Number of entities in the room's Bevy world after the latest tick.
+    pub entity_count: u32,
+}
+
+/// A client connection currently open to the module, written by `connect` and `disconnect`.
+/// An `Identity` may have several connections at once. Public for "online players" lists.
+#[spacetimedb::table(name = connection, public)]
+#[derive(Debug, Clone)]
+pub struct Connection {
+    #[primary_key]
+    pub connection_id: ConnectionId,
+    /// The client's `Identity`.
+    #[index(btree)]
+    pub identity: Identity,
+    /// When the connection was opened.
+    pub connected_at: Timestamp,
+    /// Time of the latest reducer call on this connection, at `ACTIVITY_RESOLUTION_US` precision.
+    pub last_activity: Timestamp,
 }
 
-/// Represents a player or other dynamic object in the game world.
+/// Profile of a client that entered the game, keyed by its `Identity`.
+/// Public so clients can show player names.
+#[spacetimedb::table(name = player, public)]
+#[derive(Debug, Clone)]
+pub struct Player {
+    #[primary_key]
+    pub identity: Identity,
+    /// Display name, validated by `validate_player_name`.
+    pub name: String,
+    /// `name` normalized by `player_name_key`, so names differing only in case collide.
+    #[unique]
+    pub name_key: String,
+    /// When the player first entered the game.
+    pub created_at: Timestamp,
+    /// When the player last entered, connected or disconnected.
+    pub last_seen: Timestamp,
+    /// Play time of finished sessions, in microseconds.
+    pub total_play_time_us: u64,
+    /// Start of the current session, `None` while disconnected.
+    pub session_started_at: Option<Timestamp>,
+    /// The player's `Entity` row, set once the player entered a room.
+    /// May point to a deleted row after the entity expired; use `player_entity`.
+    pub entity_id: Option<u32>,
+}
+
+/// Represents a player, NPC, projectile or pickup in the game world.
 /// Marked `public` so clients can subscribe to it.
 #[spacetimedb::table(name = entity, public)]
 #[derive(Debug, Clone)]
 pub struct Entity {
     #[primary_key]
-    /// The unique ID for this entity. In this setup, it matches the Bevy `Entity::index()`.
+    /// The unique ID for this entity, allocated by the Bevy-side `StdbEntityMap`.
     pub entity_id: u32,
     /// Current position in the game world.
     pub position: Vec2,
-    /// The SpacetimeDB `Identity` of the client that owns/controls this entity.
-    #[unique]
-    pub owner_identity: Identity,
+    /// The SpacetimeDB `Identity` of the client that owns/controls this entity, if any.
+    /// References the owner's `Player` row, e.g. for its name.
+    pub owner_identity: Option<Identity>,
+    /// The room whose Bevy world simulates this entity.
+    #[index(btree)]
+    pub room_id: u32,
+    /// What this entity is; selects the prefab it is spawned from.
+    pub kind: EntityKind,
+}
+
+/// Sequence number of the latest input applied to each player entity.
+/// Public so clients can reconcile their predicted movement against authoritative positions.
+#[spacetimedb::table(name = input_ack, public)]
+#[derive(Debug, Clone)]
+pub struct InputAck {
+    #[primary_key]
+    /// The `Entity` row this acknowledgement belongs to.
+    pub entity_id: u32,
+    /// The `seq` of the latest `receive_player_input` call applied by the server.
+    pub last_processed_input: u32,
+    /// The room of the entity, so clients can subscribe to the acknowledgements of their room.
+    #[index(btree)]
+    pub room_id: u32,
+}
+
+// --- Table Reads from Bevy ---
+
+/// Lets Bevy systems read the configuration through `StdbDb`.
+impl StdbReadRow for ServerConfig {
+    type Key = u32;
+
+    fn find(ctx: &ReducerContext, key: &u32) -> Option<Self> {
+        ctx.db.server_config().id().find(key)
+    }
+
+    fn all(ctx: &ReducerContext) -> Vec<Self> {
+        ctx.db.server_config().iter().collect()
+    }
+}
+
+/// Lets Bevy systems read `Entity` rows through `StdbDb`.
+impl StdbReadRow for Entity {
+    type Key = u32;
+
+    fn find(ctx: &ReducerContext, key: &u32) -> Option<Self> {
+        ctx.db.entity().entity_id().find(key)
+    }
+
+    fn all(ctx: &ReducerContext) -> Vec<Self> {
+        ctx.db.entity().iter().collect()
+    }
+}
+
+/// Lets Bevy systems and the `LastProcessedInput` mirror read `InputAck` rows.
+impl StdbReadRow for InputAck {
+    type Key = u32;
+
+    fn find(ctx: &ReducerContext, key: &u32) -> Option<Self> {
+        ctx.db.input_ack().entity_id().find(key)
+    }
+
+    fn all(ctx: &ReducerContext) -> Vec<Self> {
+        ctx.db.input_ack().iter().collect()
+    }
+}
+
+/// Lets Bevy systems read `Player` rows, e.g. to look up the name of an entity's owner.
+impl StdbReadRow for Player {
+    type Key = Identity;
+
+    fn find(ctx: &ReducerContext, key: &Identity) -> Option<Self> {
+        ctx.db.player().identity().find(key)
+    }
+
+    fn all(ctx: &ReducerContext) -> Vec<Self> {
+        ctx.db.player().iter().collect()
+    }
+}
+
+/// Lets queued `DbCommand`s write `Entity` rows through a `DbBackend`.
+impl StdbRow for Entity {
+    const TABLE: &'static str = "entity";
+
+    fn key(&self) -> u32 {
+        self.entity_id
+    }
+
+    fn insert(ctx: &ReducerContext, row: Self) -> Result<(), String> {
+        ctx.db.entity().try_insert(row)?;
+        Ok(())
+    }
+
+    fn update(ctx: &ReducerContext, row: Self) -> bool {
+        if ctx.db.entity().entity_id().find(row.entity_id).is_none() {
+            return false;
+        }
+        ctx.db.entity().entity_id().update(row);
+        true
+    }
+
+    fn delete(ctx: &ReducerContext, key: u32) -> bool {
+        ctx.db.entity().entity_id().delete(key)
+    }
+}
+
+/// Lets queued `DbCommand`s write `InputAck` rows through a `DbBackend`.
+impl StdbRow for InputAck {
+    const TABLE: &'static str = "input_ack";
+
+    fn key(&self) -> u32 {
+        self.entity_id
+    }
+
+    fn insert(ctx: &ReducerContext, row: Self) -> Result<(), String> {
+        ctx.db.input_ack().try_insert(row)?;
+        Ok(())
+    }
+
+    fn update(ctx: &ReducerContext, row: Self) -> bool {
+        if ctx.db.input_ack().entity_id().find(row.entity_id).is_none() {
+            return false;
+        }
+        ctx.db.input_ack().entity_id().update(row);
+        true
+    }
+
+    fn delete(ctx: &ReducerContext, key: u32) -> bool {
+        ctx.db.input_ack().entity_id().delete(key)
+    }
 }
 
 // --- Bevy Event Definitions ---
 
-/// Bevy event triggered by the `enter_game` reducer to request spawning
-/// a new player entity within the Bevy world.
+/// Bevy event requesting a new entity within the Bevy world, built from the prefab
+/// registered for its `kind`. Triggered by `enter_game` for players and `spawn_entity`
+/// for everything else.
 #[derive(Debug, Clone, Event)]
 pub struct InstantiateEntityEvent {
-    pub owner_identity: Identity,
+    pub kind: EntityKind,
+    pub owner_identity: Option<Identity>,
     pub position: Vec2,
 }
 
 /// Bevy event triggered by the `receive_player_input` reducer when a client
-/// sends movement input.
-#[derive(Debug, Clone, Event)]
+/// sends movement input. Sent wrapped in `FromReducer`, whose `meta.sender`
+/// identifies the player.
+#[derive(Debug, Clone)]
 pub struct PlayerInputEvent {
-    /// The ID of the entity this input applies to.
-    pub player_id: u32,
     /// The input direction vector.
     pub direction: Vec2,
+    /// Client-assigned sequence number, increasing with each input of a session.
+    pub seq: u32,
+}
+
+/// Bevy event sent (wrapped in `FromReducer`) when a player with an entity connects or
+/// disconnects. The player is named explicitly, since the sending reducer may have been
+/// called by someone else (e.g. `prune_connections`).
+#[derive(Debug, Clone)]
+pub enum PlayerPresence {
+    /// The player connected; its inputs are numbered from scratch again.
+    Connected { identity: Identity },
+    /// The player disconnected; its entity is despawned unless it reconnects within `grace`.
+    Disconnected { identity: Identity, grace: Duration },
+}
+
+impl PlayerPresence {
+    /// The player whose presence changed.
+    pub fn identity(&self) -> Identity {
+        match self {
+            PlayerPresence::Connected { identity }
+            | PlayerPresence::Disconnected { identity, .. } => *identity,
+        }
+    }
 }
 
 // --- Bevy Component Definitions ---
@@ -95,15 +449,129 @@ pub struct PlayerInputEvent {
 #[derive(Component, Debug, Clone)] // Added Debug, Clone
 pub struct Position(pub Vec2);
 
-// --- SpacetimeDB Reducers ---
+/// Bevy component holding the SpacetimeDB `Identity` that owns/controls an entity.
+/// Kept indexed in the `OwnerIndex` resource through component hooks.
+#[derive(Component, Debug, Clone)]
+#[component(on_insert = index_owner, on_replace = unindex_owner)]
+pub struct Owner(pub Identity);
 
-/// Reducer called once when the SpacetimeDB module initializes.
-/// Sets up the Bevy App instance, schedules the tick, and registers Bevy systems/events.
-#[spacetimedb::reducer(init)]
-pub fn init(ctx: &ReducerContext) -> Result<(), String> {
-    log::info!("Initializing Spacetime Module and Bevy App...");
+/// Bevy resource mapping each owner `Identity` to its Bevy entity.
+#[derive(Resource, Debug, Default)]
+pub struct OwnerIndex(pub HashMap<Identity, bevy::prelude::Entity>);
+
+// Hook adding a newly inserted `Owner` to the `OwnerIndex`.
+fn index_owner(mut world: DeferredWorld, context: HookContext) {
+    let Some(identity) = world.get::<Owner>(context.entity).map(|owner| owner.0) else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<OwnerIndex>() {
+        index.0.insert(identity, context.entity);
+    }
+}
+
+// Hook removing a replaced or removed `Owner` from the `OwnerIndex`.
+fn unindex_owner(mut world: DeferredWorld, context: HookContext) {
+    let Some(identity) = world.get::<Owner>(context.entity).map(|owner| owner.0) else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<OwnerIndex>() {
+        if index.0.get(&identity) == Some(&context.entity) {
+            index.0.remove(&identity);
+        }
+    }
+}
+
+/// Bevy component holding the latest input direction of a player.
+/// Applied to `Position` every fixed step by `apply_player_movement_system`.
+#[derive(Component, Debug, Clone, Default)]
+pub struct MoveIntent(pub Vec2);
+
+/// Bevy component buffering a player's inputs, as `(seq, direction)` in sequence order,
+/// until `consume_input_buffer_system` applies them, one per fixed step.
+#[derive(Component, Debug, Clone, Default)]
+pub struct InputBuffer(pub VecDeque<(u32, Vec2)>);
+
+/// Bevy component holding the sequence number of the latest input applied to a player.
+/// Mirrored into the `InputAck` table.
+#[derive(Component, Debug, Clone, Default)]
+pub struct LastProcessedInput(pub u32);
+
+/// Bevy component counting down the grace period of a disconnected player.
+/// `None` while the player is connected; the entity is despawned when the timer finishes.
+#[derive(Component, Debug, Clone, Default)]
+pub struct DisconnectGrace(pub Option<Timer>);
+
+/// Mirrors `LastProcessedInput` into the `InputAck` table, one row per player entity.
+impl StdbMirror for LastProcessedInput {
+    const TABLE: &'static str = "input_ack";
 
-    // --- Bevy App Setup ---
+    fn insert_row(db: &mut dyn DbBackend, id: u32, value: &Self) -> Result<Option<u32>, String> {
+        // Rehydrated entities already have a row, so inserts are upserts.
+        Self::update_row(db, id, value)
+    }
+
+    fn update_row(db: &mut dyn DbBackend, id: u32, value: &Self) -> Result<Option<u32>, String> {
+        let last_processed_input = value.0;
+        match db.find::<InputAck>(id) {
+            Some(ack) => {
+                db.update(InputAck { last_processed_input, ..ack })?;
+            }
+            None => {
+                // A new acknowledgement takes the room of its entity row.
+                let entity = db
+                    .find::<Entity>(id)
+                    .ok_or_else(|| format!("No entity row {} to acknowledge input for", id))?;
+                let room_id = entity.room_id;
+                db.insert(InputAck { entity_id: id, last_processed_input, room_id })?;
+            }
+        }
+        Ok(None)
+    }
+
+    fn delete_row(db: &mut dyn DbBackend, id: u32) -> Result<Option<u32>, String> {
+        db.delete::<InputAck>(id)?;
+        Ok(None)
+    }
+}
+
+/// Mirrors `Position` into the `position` column of the SpacetimeDB `Entity` table.
+/// The row itself is inserted by `instantiate_entity_system`, so only updates are written here.
+/// Positions are rounded to `POSITION_QUANTUM` and only written after moving `POSITION_EPSILON`.
+impl StdbMirror for Position {
+    const TABLE: &'static str = "entity";
+
+    fn quantize(&self) -> Self {
+        let round = |v: f32| (v / POSITION_QUANTUM).round() * POSITION_QUANTUM;
+        Position(Vec2 { x: round(self.0.x), y: round(self.0.y) })
+    }
+
+    fn exceeds_threshold(&self, last_written: &Self) -> bool {
+        let dx = self.0.x - last_written.0.x;
+        let dy = self.0.y - last_written.0.y;
+        dx * dx + dy * dy > POSITION_EPSILON * POSITION_EPSILON
+    }
+
+    fn update_row(db: &mut dyn DbBackend, id: u32, value: &Self) -> Result<Option<u32>, String> {
+        log::trace!("Executing STDB position update for entity {} to {:?}", id, value.0);
+        // Find the SpacetimeDB row by its primary key (entity_id).
+        if let Some(mut entity_row) = db.find::<Entity>(id) {
+            // Update the position field and write the row back by its primary key.
+            entity_row.position = value.0;
+            db.update(entity_row)?;
+            log::trace!("Updated STDB entity {} position.", id);
+        } else {
+            // This might happen if the entity was deleted between the Bevy update and STDB update.
+            log::warn!("Could not find SpacetimeDB entity {} to update position.", id);
+        }
+        Ok(None)
+    }
+}
+
+// --- Bevy App Setup ---
+
+/// Builds the Bevy App of one room: plugins, events, systems and row loaders.
+/// Called when a room is created, and again on its first tick after a module restart.
+fn build_bevy_app(ctx: &ReducerContext, _room: RoomId) -> App {
     let mut app = App::new();
     app.add_plugins(
         // Use MinimalPlugins and disable features not needed for server-side logic.
@@ -112,117 +580,593 @@ pub fn init(ctx: &ReducerContext) -> Result<(), String> {
             .disable::<ScheduleRunnerPlugin>() // No need for Bevy to run its own schedule loop
             .disable::<TimePlugin>(), // SpacetimeDB handles time/ticks
     );
+    // Rooms are rebuilt one at a time; continue entity IDs where the previous module
+    // instance stopped, and make sure none reuses an ID of another room's rows.
+    seed_entity_ids(ctx);
+    // Step `FixedUpdate` at the tick interval; late ticks run catch-up steps.
+    app.insert_resource(Time::<Fixed>::from_duration(tick_interval(ctx)));
     // Add the integration plugin, which sets up the CommandQueue resource.
-    app.add_plugins(SpacetimeDbServerPlugin);
+    // Failed DB commands are logged and reported as `DbCommandFailed` events.
+    app.add_plugins(SpacetimeDbServerPlugin::default().with_error_policy(DbErrorPolicy::Continue));
+    // Mirror Bevy `Position` changes into the SpacetimeDB `Entity` table.
+    app.add_plugins(MirrorPlugin::<Position>::default());
+    // Mirror acknowledged input sequence numbers into the `InputAck` table.
+    app.add_plugins(MirrorPlugin::<LastProcessedInput>::default());
 
     // Register Bevy events used for communication between STDB reducers and Bevy systems.
     app.add_event::<InstantiateEntityEvent>();
-    app.add_event::<PlayerInputEvent>();
+    app.add_event::<FromReducer<PlayerInputEvent>>();
+    app.add_event::<FromReducer<PlayerPresence>>();
+    app.init_resource::<OwnerIndex>();
 
     // Add Bevy systems.
+    // Buffer received inputs before `FixedUpdate` runs this frame.
+    app.add_systems(PreUpdate, buffer_player_input_system);
+    // Apply one buffered input per step, then move players by their input direction.
+    app.add_systems(
+        FixedUpdate,
+        (consume_input_buffer_system, apply_player_movement_system).chain(),
+    );
     app.add_systems(
         Update, // Run these systems during the Bevy App::update() cycle.
         (
+            // Clean up Bevy entities whose STDB insert failed last tick.
+            despawn_failed_inserts_system,
+            // Start or cancel grace periods of players leaving and returning.
+            track_player_presence_system,
+            // Despawn players whose grace period ran out and delete their rows.
+            expire_disconnected_players_system,
             // System to handle InstantiateEntityEvent and queue STDB insertion.
             instantiate_entity_system,
-            // Apply movement input to Bevy Position components.
-            apply_player_movement_system,
-            // Detect changes in Bevy Position and queue STDB updates.
-            update_stdb_position_system,
         )
-            // Define execution order: apply movement *before* checking for changes to update STDB.
+            // Position changes are mirrored to STDB afterwards, in PostUpdate.
             .chain(), // Apply .chain() for clear sequential ordering
     );
 
-    // Store the configured Bevy App instance in the static variable.
-    // SAFETY: This assumes `init` is called exactly once by SpacetimeDB.
-    let app_ptr = BEVY_APP.0.get();
-    unsafe {
-        *app_ptr = Some(app);
+    // Register the prefab of each entity kind. This also rebuilds Bevy entities from
+    // existing `Entity` rows after a module restart.
+    app.add_prefab::<PlayerPrefab>()
+        .add_prefab::<NpcPrefab>()
+        .add_prefab::<ProjectilePrefab>()
+        .add_prefab::<PickupPrefab>();
+
+    app
+}
+
+/// Raises the module-wide entity ID counter to the persisted `next_entity_id`, and above
+/// every existing `Entity` row of all rooms. The counter lives in module memory and starts
+/// over when the module restarts.
+fn seed_entity_ids(ctx: &ReducerContext) {
+    if let Some(config) = ctx.db.server_config().id().find(0) {
+        reserve_row_ids(config.next_entity_id);
+    }
+    if let Some(max_id) = ctx.db.entity().iter().map(|entity| entity.entity_id).max() {
+        reserve_row_ids(max_id.saturating_add(1));
+    }
+}
+
+/// Stores the entity ID counter in `ServerConfig` if entities were allocated since it was
+/// last stored. Called after each batch of `Entity` inserts, in the same transaction.
+fn persist_entity_ids(ctx: &ReducerContext) {
+    let Some(mut config) = ctx.db.server_config().id().find(0) else {
+        return;
+    };
+    let next = next_row_id();
+    if next > config.next_entity_id {
+        config.next_entity_id = next;
+        ctx.db.server_config().id().update(config);
+    }
+}
+
+/// Reads the configured tick interval, falling back to the default before `init` stored it.
+fn tick_interval(ctx: &ReducerContext) -> Duration {
+    let interval_ms = ctx
+        .db
+        .server_config()
+        .id()
+        .find(0)
+        .map_or(DEFAULT_TICK_INTERVAL_MS, |config| config.tick_interval_ms);
+    Duration::from_millis(interval_ms)
+}
+
+/// Reads the configured disconnect grace period, falling back to the default before `init`
+/// stored it.
+fn disconnect_grace(ctx: &ReducerContext) -> Duration {
+    let grace_ms = ctx
+        .db
+        .server_config()
+        .id()
+        .find(0)
+        .map_or(DEFAULT_DISCONNECT_GRACE_MS, |config| config.disconnect_grace_ms);
+    Duration::from_millis(grace_ms)
+}
+
+/// Records activity on the caller's connection, at most once per `ACTIVITY_RESOLUTION_US`.
+fn touch_connection(ctx: &ReducerContext) {
+    let Some(connection_id) = ctx.connection_id else {
+        return;
+    };
+    let Some(mut connection) = ctx.db.connection().connection_id().find(connection_id) else {
+        return;
+    };
+    let idle_us = ctx.timestamp.to_micros_since_unix_epoch()
+        - connection.last_activity.to_micros_since_unix_epoch();
+    if idle_us >= ACTIVITY_RESOLUTION_US {
+        connection.last_activity = ctx.timestamp;
+        ctx.db.connection().connection_id().update(connection);
+    }
+}
+
+/// Returns `true` if `identity` has at least one open connection.
+fn is_online(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db.connection().identity().filter(identity).next().is_some()
+}
+
+/// Checks that `name` is a valid player name: `PLAYER_NAME_LENGTH` characters of ASCII
+/// letters, digits, `_` or `-`.
+fn validate_player_name(name: &str) -> Result<(), String> {
+    if !PLAYER_NAME_LENGTH.contains(&name.chars().count()) {
+        return Err(format!(
+            "Player name must be {} to {} characters long",
+            PLAYER_NAME_LENGTH.start(),
+            PLAYER_NAME_LENGTH.end()
+        ));
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err("Player name may only contain letters, digits, '_' and '-'".to_string());
+    }
+    Ok(())
+}
+
+/// Returns the key under which a valid player name is unique: its lowercase form,
+/// so "Alice" and "alice" can not belong to different players.
+fn player_name_key(name: &str) -> String {
+    name.to_ascii_lowercase()
+}
+
+/// Creates the `Player` row of the caller, or renames it and starts a new session.
+fn upsert_player(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    validate_player_name(&name)?;
+    let name_key = player_name_key(&name);
+    if let Some(holder) = ctx.db.player().name_key().find(&name_key) {
+        if holder.identity != ctx.sender {
+            return Err(format!("Player name '{}' is already taken", name));
+        }
     }
-    log::info!("Bevy App initialized and stored globally.");
 
-    // --- SpacetimeDB Initialization ---
-    // Schedule the first tick. `process_tick` will be called repeatedly.
+    match ctx.db.player().identity().find(ctx.sender) {
+        Some(mut player) => {
+            player.name = name;
+            player.name_key = name_key;
+            player.last_seen = ctx.timestamp;
+            player.session_started_at.get_or_insert(ctx.timestamp);
+            ctx.db.player().identity().update(player);
+        }
+        None => {
+            ctx.db.player().try_insert(Player {
+                identity: ctx.sender,
+                name,
+                name_key,
+                created_at: ctx.timestamp,
+                last_seen: ctx.timestamp,
+                total_play_time_us: 0,
+                session_started_at: Some(ctx.timestamp),
+                entity_id: None,
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the `Entity` row of the player `identity`, if it still exists.
+fn player_entity(ctx: &ReducerContext, identity: Identity) -> Option<Entity> {
+    let entity_id = ctx.db.player().identity().find(identity)?.entity_id?;
+    // Entity IDs are never reused, so a row with this ID is still the player's own.
+    ctx.db
+        .entity()
+        .entity_id()
+        .find(entity_id)
+        .filter(|entity| entity.owner_identity == Some(identity))
+}
+
+/// Returns the server configuration if the caller is the server admin.
+fn require_admin(ctx: &ReducerContext, action: &str) -> Result<ServerConfig, String> {
+    let config = ctx
+        .db
+        .server_config()
+        .id()
+        .find(0)
+        .ok_or("Server configuration is missing")?;
+    if ctx.sender != config.admin_identity {
+        log::warn!("Identity {:?} is not allowed to {}.", ctx.sender, action);
+        return Err(format!("Only the server admin can {}", action));
+    }
+    Ok(config)
+}
+
+/// Inserts the row of `room`, builds its Bevy App and schedules its first tick.
+fn open_room(ctx: &ReducerContext, room: RoomId) -> Result<(), String> {
+    ctx.db.room().try_insert(Room {
+        room_id: room,
+        created_at: ctx.timestamp,
+    })?;
+    rehydrate_room_if_missing(room, ctx, build_bevy_app)?;
     ctx.db.scheduled_tick().try_insert(ScheduledTick {
-        scheduled_id: 0, // Start ID at 0
-        scheduled_at: ScheduleAt::Interval(Duration::from_millis(16).into()), // Approx 60 FPS
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(tick_interval(ctx).into()),
+        room_id: room,
     })?;
-    log::info!("Initial SpacetimeDB tick scheduled.");
+    log::info!("Room {} opened.", room);
+    Ok(())
+}
+
+// --- SpacetimeDB Reducers ---
+
+/// Reducer called once when the SpacetimeDB module initializes.
+/// Sets up the Bevy App instance, schedules the tick, and registers Bevy systems/events.
+#[spacetimedb::reducer(init)]
+pub fn init(ctx: &ReducerContext) -> Result<(), String> {
+    log::info!("Initializing Spacetime Module and Bevy App...");
+
+    // Store the default configuration; the publisher becomes the admin.
+    ctx.db.server_config().try_insert(ServerConfig {
+        id: 0,
+        tick_interval_ms: DEFAULT_TICK_INTERVAL_MS,
+        admin_identity: ctx.sender,
+        next_entity_id: next_row_id(),
+        disconnect_grace_ms: DEFAULT_DISCONNECT_GRACE_MS,
+    })?;
+
+    // Open the default room: its Bevy App is handed over to the integration library,
+    // which owns it from now on, and its first tick is scheduled.
+    open_room(ctx, DEFAULT_ROOM)?;
+    log::info!("Default room initialized.");
 
     log::info!("Spacetime Module initialization complete.");
     Ok(())
 }
 
-/// Reducer called when a client connects.
+/// Admin-only reducer creating a new room with its own Bevy world and tick.
+#[spacetimedb::reducer]
+pub fn create_room(ctx: &ReducerContext, room_id: u32) -> Result<(), String> {
+    require_admin(ctx, "create rooms")?;
+    if ctx.db.room().room_id().find(room_id).is_some() {
+        return Err(format!("Room {} already exists", room_id));
+    }
+    open_room(ctx, room_id)
+}
+
+/// Admin-only reducer changing the tick interval of all rooms at runtime.
+/// Reschedules `process_tick` and updates Bevy's fixed timesteps to match.
+#[spacetimedb::reducer]
+pub fn set_tick_rate(ctx: &ReducerContext, tick_interval_ms: u64) -> Result<(), String> {
+    let mut config = require_admin(ctx, "change the tick rate")?;
+    if !TICK_INTERVAL_RANGE_MS.contains(&tick_interval_ms) {
+        return Err(format!(
+            "Tick interval must be within {:?} ms, got {}",
+            TICK_INTERVAL_RANGE_MS, tick_interval_ms
+        ));
+    }
+
+    config.tick_interval_ms = tick_interval_ms;
+    ctx.db.server_config().id().update(config);
+    let interval = Duration::from_millis(tick_interval_ms);
+
+    // Replace the scheduled tick rows so `process_tick` runs at the new interval.
+    let scheduled_ids: Vec<u64> = ctx
+        .db
+        .scheduled_tick()
+        .iter()
+        .map(|tick| tick.scheduled_id)
+        .collect();
+    for scheduled_id in scheduled_ids {
+        ctx.db.scheduled_tick().scheduled_id().delete(scheduled_id);
+    }
+    let rooms: Vec<RoomId> = ctx.db.room().iter().map(|room| room.room_id).collect();
+    for room in rooms {
+        ctx.db.scheduled_tick().try_insert(ScheduledTick {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(interval.into()),
+            room_id: room,
+        })?;
+
+        // Keep the room's fixed timestep in step with the tick interval.
+        let timestep_action = create_resource_mut_action(move |time: &mut Time<Fixed>| {
+            time.set_timestep(interval);
+        });
+        schedule_room_action_from(room, ctx, "set_tick_rate", timestep_action);
+    }
+    log::info!("Tick interval changed to {} ms.", tick_interval_ms);
+    Ok(())
+}
+
+/// Admin-only reducer changing how long a disconnected player's entity is kept.
+/// Applies to disconnections after the change.
+#[spacetimedb::reducer]
+pub fn set_disconnect_grace(ctx: &ReducerContext, grace_ms: u64) -> Result<(), String> {
+    let mut config = require_admin(ctx, "change the disconnect grace period")?;
+    if !DISCONNECT_GRACE_RANGE_MS.contains(&grace_ms) {
+        return Err(format!(
+            "Disconnect grace period must be within {:?} ms, got {}",
+            DISCONNECT_GRACE_RANGE_MS, grace_ms
+        ));
+    }
+    config.disconnect_grace_ms = grace_ms;
+    ctx.db.server_config().id().update(config);
+    log::info!("Disconnect grace period changed to {} ms.", grace_ms);
+    Ok(())
+}
+
+/// Admin-only reducer deleting connections without activity for `max_idle_ms`,
+/// e.g. left over after a host crash. Their players are treated as disconnected.
+#[spacetimedb::reducer]
+pub fn prune_connections(ctx: &ReducerContext, max_idle_ms: u64) -> Result<(), String> {
+    require_admin(ctx, "prune connections")?;
+    let max_idle_us = i64::try_from(max_idle_ms).unwrap_or(i64::MAX).saturating_mul(1000);
+    let cutoff_us = ctx.timestamp.to_micros_since_unix_epoch().saturating_sub(max_idle_us);
+    let stale: Vec<Connection> = ctx
+        .db
+        .connection()
+        .iter()
+        .filter(|connection| connection.last_activity.to_micros_since_unix_epoch() < cutoff_us)
+        .collect();
+    for connection in stale {
+        log::info!(
+            "Pruning stale connection {:?} of {:?}.",
+            connection.connection_id,
+            connection.identity
+        );
+        ctx.db.connection().connection_id().delete(connection.connection_id);
+        if !is_online(ctx, connection.identity) {
+            end_player_session(ctx, connection.identity, "prune_connections");
+        }
+    }
+    Ok(())
+}
+
+/// Reducer called when a client connects. Records the connection; on the identity's first
+/// connection, a player returning within its grace period resumes its existing entity.
 #[spacetimedb::reducer(client_connected)]
-pub fn connect(_ctx: &ReducerContext) -> Result<(), String> {
-    // Placeholder: Add logic if needed when a client connects.
+pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
+    let already_online = is_online(ctx, ctx.sender);
+    if let Some(connection_id) = ctx.connection_id {
+        ctx.db.connection().try_insert(Connection {
+            connection_id,
+            identity: ctx.sender,
+            connected_at: ctx.timestamp,
+            last_activity: ctx.timestamp,
+        })?;
+    }
+    if already_online {
+        return Ok(()); // The player's session is already running.
+    }
+
+    if let Some(mut player) = ctx.db.player().identity().find(ctx.sender) {
+        player.last_seen = ctx.timestamp;
+        player.session_started_at = Some(ctx.timestamp);
+        ctx.db.player().identity().update(player);
+    }
+    if let Some(player) = player_entity(ctx, ctx.sender) {
+        let presence = PlayerPresence::Connected { identity: ctx.sender };
+        let presence_action = create_send_reducer_event_action(presence);
+        schedule_room_action_from(player.room_id, ctx, "connect", presence_action);
+    }
     Ok(())
 }
 
-/// Reducer called when a client disconnects.
+/// Reducer called when a client disconnects. Deletes the connection; once the identity's
+/// last connection is closed, starts the grace period after which the player's entity is
+/// despawned and its row deleted.
 #[spacetimedb::reducer(client_disconnected)]
-pub fn disconnect(_ctx: &ReducerContext) -> Result<(), String> {
-    // Placeholder: Add logic if needed, e.g., remove the player's entity.
-    // Note: Need to find the entity associated with ctx.sender identity.
+pub fn disconnect(ctx: &ReducerContext) -> Result<(), String> {
+    if let Some(connection_id) = ctx.connection_id {
+        ctx.db.connection().connection_id().delete(connection_id);
+    }
+    if !is_online(ctx, ctx.sender) {
+        end_player_session(ctx, ctx.sender, "disconnect");
+    }
     Ok(())
 }
 
+/// Ends the session of the player `identity` after its last connection closed.
+fn end_player_session(ctx: &ReducerContext, identity: Identity, reducer: &'static str) {
+    INPUT_LIMITER.lock().forget(identity);
+    if let Some(mut player) = ctx.db.player().identity().find(identity) {
+        // Close the session, adding its length to the player's play time.
+        if let Some(started_at) = player.session_started_at.take() {
+            let session_us = ctx.timestamp.to_micros_since_unix_epoch()
+                - started_at.to_micros_since_unix_epoch();
+            player.total_play_time_us += session_us.max(0) as u64;
+        }
+        player.last_seen = ctx.timestamp;
+        ctx.db.player().identity().update(player);
+    }
+    if let Some(player) = player_entity(ctx, identity) {
+        let grace = disconnect_grace(ctx);
+        let presence = PlayerPresence::Disconnected { identity, grace };
+        let presence_action = create_send_reducer_event_action(presence);
+        schedule_room_action_from(player.room_id, ctx, reducer, presence_action);
+        log::info!(
+            "Player {:?} disconnected; entity {} kept for {:?}.",
+            identity,
+            player.entity_id,
+            grace
+        );
+    }
+}
+
 /// The main integration point between SpacetimeDB's tick and Bevy's update cycle.
-/// This reducer is scheduled to run at regular intervals by the `ScheduledTick` table.
+/// This reducer is scheduled to run at regular intervals by the `ScheduledTick` table,
+/// once per room; each call only updates the Bevy world of the tick's room.
 #[spacetimedb::reducer]
-pub fn process_tick(ctx: &ReducerContext, _tick: ScheduledTick) -> Result<(), String> {
-    // Retrieve the Bevy App instance from the static storage.
-    // SAFETY: Relies on `init` having run and `process_tick` being serialized.
-    let app_ptr = BEVY_APP.0.get();
-    let app = unsafe { (*app_ptr).as_mut() };
-
-    if let Some(app) = app {
-        // 1. Process Actions Queued from STDB -> Bevy:
-        //    Execute any actions (like sending events) that were scheduled by reducers
-        //    since the last tick using `schedule_bevy_action`.
-        process_bevy_actions(app);
-
-        // 2. Run Bevy's Update Cycle:
-        //    Execute all Bevy systems scheduled for the `Update` stage.
-        //    This includes systems that read events (like PlayerInputEvent)
-        //    and modify Bevy components (like Position).
-        if let Err(e) = run_bevy_update(app) {
-            log::error!("Failed to run Bevy update cycle: {}", e);
-            // Depending on the error, might want to return Err(e) here.
-        }
+pub fn process_tick(ctx: &ReducerContext, tick: ScheduledTick) -> Result<(), String> {
+    let room = tick.room_id;
+    // The App only lives in module memory; after a restart or republish it is
+    // rebuilt from the SpacetimeDB tables before the first tick runs.
+    rehydrate_room_if_missing(room, ctx, build_bevy_app)?;
 
-        // 3. Process Commands Queued from Bevy -> STDB:
-        //    Execute any SpacetimeDB operations (like table inserts/updates)
-        //    that were queued by Bevy systems during the `run_bevy_update`
-        //    using the `CommandQueue` resource.
-        if let Err(e) = process_bevy_commands(app, ctx) {
-            log::error!("Failed to process Bevy->SpacetimeDB commands: {}", e);
-            // Depending on the error, might want to return Err(e) here.
-        }
-    } else {
-        // This should not happen if `init` ran correctly.
-        log::error!("Bevy App not initialized in process_tick. Cannot update.");
-        return Err("Bevy App is not initialized".to_string());
+    // Borrow the room's Bevy App instance owned by the integration library.
+    let mut app = borrow_room_app(room).map_err(|e| {
+        log::error!("Cannot borrow Bevy App of room {} in process_tick: {}", room, e);
+        e
+    })?;
+    let app = &mut *app;
+
+    // 1. Process Actions Queued from STDB -> Bevy:
+    //    Execute any actions (like sending events) that were scheduled by reducers
+    //    for this room since the last tick using `schedule_room_action_from`.
+    let actions = process_bevy_actions(app);
+    let mut errors = 0;
+
+    // 2. Run Bevy's Update Cycle:
+    //    Execute all Bevy systems scheduled for the `Update` stage.
+    //    This includes systems that read events (like PlayerInputEvent)
+    //    and modify Bevy components (like Position).
+    //    The reducer timestamp drives Bevy's `Time` and `FixedUpdate` steps.
+    if let Err(e) = run_bevy_update(app, ctx) {
+        log::error!("Failed to run Bevy update cycle: {}", e);
+        // Depending on the error, might want to return Err(e) here.
+        errors += 1;
     }
 
+    // 3. Process Commands Queued from Bevy -> STDB:
+    //    Execute any SpacetimeDB operations (like table inserts/updates)
+    //    that were queued by Bevy systems during the `run_bevy_update`
+    //    using the `CommandQueue` resource.
+    //    Only fails under `DbErrorPolicy::FailTick`, in which case returning the
+    //    error rolls back the whole tick transaction.
+    let commands = process_bevy_commands(app, ctx).map_err(|e| {
+        log::error!("Failed to process Bevy->SpacetimeDB commands: {}", e);
+        e
+    })?;
+    persist_entity_ids(ctx);
+
+    // 4. Publish the tick's metrics.
+    let entity_count = app.world().entities().len();
+    record_tick_stats(ctx, room, actions, commands, errors + commands.failed, entity_count);
+
     Ok(())
 }
 
-/// Reducer called by a client to signal their intent to join the game.
+/// Folds the counts of the current tick of `room` into its in-memory `TickStats`, and
+/// publishes them to the table when due.
+fn record_tick_stats(
+    ctx: &ReducerContext,
+    room: RoomId,
+    actions: usize,
+    commands: CommandStats,
+    errors: usize,
+    entity_count: u32,
+) {
+    let scheduled_interval_us = tick_interval(ctx).as_micros() as u64;
+    let actions = actions as u32;
+    let errors = errors as u32;
+    let executed = commands.executed as u32;
+
+    let mut rooms = TICK_STATS.lock();
+    let (stats, resumed) = match rooms.entry(room) {
+        MapEntry::Occupied(entry) => (entry.into_mut(), false),
+        // After a module restart, continue from the last published row.
+        MapEntry::Vacant(entry) => match ctx.db.tick_stats().room_id().find(room) {
+            Some(published) => (entry.insert(published), true),
+            None => {
+                // First tick of the room: no interval to measure yet.
+                let stats = entry.insert(TickStats {
+                    room_id: room,
+                    tick_count: 1,
+                    last_tick_at: ctx.timestamp,
+                    scheduled_interval_us,
+                    last_interval_us: 0,
+                    avg_interval_us: 0,
+                    max_interval_us: 0,
+                    last_tick_late: false,
+                    late_ticks: 0,
+                    last_actions: actions,
+                    last_commands: executed,
+                    last_commands_coalesced: commands.coalesced as u32,
+                    last_errors: errors,
+                    total_actions: u64::from(actions),
+                    total_commands: u64::from(executed),
+                    total_errors: u64::from(errors),
+                    entity_count,
+                });
+                ctx.db.tick_stats().insert(stats.clone());
+                return;
+            }
+        },
+    };
+
+    if resumed {
+        // The previous tick ran before the restart: like a first tick, take no interval sample.
+        stats.last_interval_us = 0;
+        stats.last_tick_late = false;
+    } else {
+        let elapsed_us = ctx.timestamp.to_micros_since_unix_epoch()
+            - stats.last_tick_at.to_micros_since_unix_epoch();
+        let interval_us = elapsed_us.max(0) as u64;
+        let late = interval_us * 100 > scheduled_interval_us * (100 + LATE_TICK_TOLERANCE_PERCENT);
+        if late {
+            log::warn!(
+                "Tick of room {} started {} us after the previous one (scheduled every {} us).",
+                room,
+                interval_us,
+                scheduled_interval_us
+            );
+        }
+
+        stats.avg_interval_us = if stats.tick_count == 1 {
+            interval_us
+        } else {
+            let weighted = stats.avg_interval_us * (TICK_STATS_SMOOTHING - 1) + interval_us;
+            weighted / TICK_STATS_SMOOTHING
+        };
+        stats.last_interval_us = interval_us;
+        stats.max_interval_us = stats.max_interval_us.max(interval_us);
+        stats.last_tick_late = late;
+        stats.late_ticks += u64::from(late);
+    }
+    stats.tick_count += 1;
+    stats.last_tick_at = ctx.timestamp;
+    stats.scheduled_interval_us = scheduled_interval_us;
+    stats.last_actions = actions;
+    stats.last_commands = executed;
+    stats.last_commands_coalesced = commands.coalesced as u32;
+    stats.last_errors = errors;
+    stats.total_actions += u64::from(actions);
+    stats.total_commands += u64::from(executed);
+    stats.total_errors += u64::from(errors);
+    stats.entity_count = entity_count;
+    if stats.last_tick_late || stats.tick_count % TICK_STATS_PUBLISH_TICKS == 0 {
+        ctx.db.tick_stats().room_id().update(stats.clone());
+    }
+}
+
+/// Reducer called by a client to signal their intent to join the game, in the default room.
 #[spacetimedb::reducer]
 pub fn enter_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    enter_room(ctx, name, DEFAULT_ROOM)
+}
+
+/// Reducer called by a client to join the game in a specific room.
+#[spacetimedb::reducer]
+pub fn enter_room(ctx: &ReducerContext, name: String, room_id: u32) -> Result<(), String> {
     let owner_identity = ctx.sender; // Identify the client making the request.
-    log::info!("Player '{}' ({:?}) requesting to enter game...", name, owner_identity);
+    log::info!(
+        "Player '{}' ({:?}) requesting to enter room {}...",
+        name,
+        owner_identity,
+        room_id
+    );
+    if ctx.db.room().room_id().find(room_id).is_none() {
+        return Err(format!("Room {} does not exist", room_id));
+    }
+
+    touch_connection(ctx);
+    // Record the player's profile; entering again updates the name.
+    upsert_player(ctx, name)?;
 
     // Prevent duplicate entities for the same player.
-    if ctx
-        .db
-        .entity()
-        .iter()
-        .any(|e| e.owner_identity == owner_identity)
-    {
+    if player_entity(ctx, owner_identity).is_some() {
         log::warn!(
             "Player {:?} already has an entity. Ignoring enter_game request.",
             owner_identity
@@ -230,192 +1174,559 @@ pub fn enter_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
         return Ok(());
     }
 
-    // Instead of directly inserting into STDB, schedule a Bevy event.
-    // The `instantiate_entity_system` will handle this event during the next Bevy update.
-    let instantiate_event = InstantiateEntityEvent {
-        owner_identity,
+    // Spawn the player's entity right away and remember it on the player's profile.
+    let request = InstantiateEntityEvent {
+        kind: EntityKind::Player,
+        owner_identity: Some(owner_identity),
         position: Vec2 { x: 0.0, y: 0.0 }, // Initial position
     };
-    let event_action = create_send_event_action(instantiate_event);
-
-    // Use the safe scheduling mechanism from the integration library.
-    schedule_bevy_action(event_action);
-    log::trace!(
-        "Scheduled InstantiateEntityEvent action for identity {:?}",
-        owner_identity
+    let entity_id = instantiate_in_room(ctx, room_id, "enter_room", request)?;
+    if let Some(mut player) = ctx.db.player().identity().find(owner_identity) {
+        player.entity_id = Some(entity_id);
+        ctx.db.player().identity().update(player);
+    }
+    log::info!(
+        "Player {:?} entered room {} as entity {}.",
+        owner_identity,
+        room_id,
+        entity_id
     );
 
     Ok(())
 }
 
+/// Admin reducer spawning a server-controlled entity of `kind` at (`x`, `y`) in a room.
+/// Players enter through `enter_game` instead.
+#[spacetimedb::reducer]
+pub fn spawn_entity(
+    ctx: &ReducerContext,
+    room_id: u32,
+    kind: EntityKind,
+    x: f32,
+    y: f32,
+) -> Result<(), String> {
+    require_admin(ctx, "spawn entities")?;
+    if kind == EntityKind::Player {
+        return Err("Players are spawned by entering the game".to_string());
+    }
+    if !x.is_finite() || !y.is_finite() {
+        return Err(format!("Invalid spawn position ({}, {})", x, y));
+    }
+    if ctx.db.room().room_id().find(room_id).is_none() {
+        return Err(format!("Room {} does not exist", room_id));
+    }
+
+    let request = InstantiateEntityEvent {
+        kind,
+        owner_identity: None,
+        position: Vec2 { x, y },
+    };
+    let entity_id = instantiate_in_room(ctx, room_id, "spawn_entity", request)?;
+    log::info!("Spawned {:?} entity {} in room {}.", kind, entity_id, room_id);
+    Ok(())
+}
+
+/// Spawns `request` in the Bevy world of `room` right away instead of deferring to the
+/// next tick. The `Entity` row insert runs within the calling reducer's transaction, so a
+/// failed insert fails the reducer itself. Returns the new entity ID.
+fn instantiate_in_room(
+    ctx: &ReducerContext,
+    room: RoomId,
+    reducer: &'static str,
+    request: InstantiateEntityEvent,
+) -> Result<u32, String> {
+    rehydrate_room_if_missing(room, ctx, build_bevy_app)?;
+    let entity_id = run_room_now(room, ctx, reducer, |world| {
+        world
+            .run_system_cached_with(instantiate_entity_now, request)
+            .map_err(|e| e.to_string())?
+    })?;
+    persist_entity_ids(ctx);
+    Ok(entity_id)
+}
+
 /// Reducer called by a client to send movement input.
+/// `seq` numbers the client's inputs; the latest applied one is published in `InputAck`.
 #[spacetimedb::reducer]
-pub fn receive_player_input(ctx: &ReducerContext, x: f32, y: f32) -> Result<(), String> {
-    let player_identity = ctx.sender;
+pub fn receive_player_input(ctx: &ReducerContext, x: f32, y: f32, seq: u32) -> Result<(), String> {
     log::trace!(
-        "Received input ({}, {}) from identity {:?}",
+        "Received input #{} ({}, {}) from identity {:?}",
+        seq,
         x,
         y,
-        player_identity
+        ctx.sender
     );
 
-    // Find the SpacetimeDB entity associated with the sending client.
-    let entity = ctx
-        .db
-        .entity().owner_identity().find(player_identity);
+    touch_connection(ctx);
 
-    if entity.is_none() {
-        log::warn!("Received input from identity {:?} which has no associated Entity.", player_identity);
+    // Drop calls beyond the client's budget, then reject malformed directions.
+    if !INPUT_LIMITER.lock().admit(ctx.sender, ctx.timestamp)? {
         return Ok(());
-    } 
-
-    let player_entity_id = entity.unwrap().entity_id;
+    }
+    let direction = sanitize_direction(x, y)?;
 
-    // Schedule a Bevy event to handle the input within the Bevy world.
-    // The `apply_player_movement_system` will process this.
-    let input_event = PlayerInputEvent {
-        player_id: player_entity_id,
-        direction: Vec2 { x, y },
+    // Route the input to the room the sender's entity lives in.
+    let Some(player) = player_entity(ctx, ctx.sender) else {
+        log::warn!("Received input from identity {:?} which has no associated Entity.", ctx.sender);
+        return Ok(());
     };
-    let event_action = create_send_event_action(input_event);
 
-    schedule_bevy_action(event_action);
-    log::trace!(
-        "Scheduled PlayerInputEvent action for entity ID {}",
-        player_entity_id
-    );
+    // Schedule a Bevy event to handle the input within the room's Bevy world.
+    // The sender's entity is resolved on the Bevy side from the attached `ReducerMeta`.
+    // The `buffer_player_input_system` will process this.
+    let input_event = PlayerInputEvent { direction, seq };
+    let event_action = create_send_reducer_event_action(input_event);
+
+    schedule_room_action_from(player.room_id, ctx, "receive_player_input", event_action);
+    log::trace!("Scheduled PlayerInputEvent action for identity {:?}", ctx.sender);
 
     Ok(())
 }
 
+/// Validates an input direction: rejects non-finite components and scales
+/// directions longer than `MAX_INPUT_MAGNITUDE` down to it.
+fn sanitize_direction(x: f32, y: f32) -> Result<Vec2, String> {
+    if !x.is_finite() || !y.is_finite() {
+        return Err(format!("Invalid input direction ({}, {})", x, y));
+    }
+    let length = x.hypot(y);
+    if length > MAX_INPUT_MAGNITUDE {
+        let scale = MAX_INPUT_MAGNITUDE / length;
+        return Ok(Vec2 { x: x * scale, y: y * scale });
+    }
+    Ok(Vec2 { x, y })
+}
+
 // --- Bevy Systems ---
 
 /// Bevy system that processes `InstantiateEntityEvent`s.
-/// It spawns a corresponding Bevy entity with a `Position` component
-/// and queues a command to insert the entity data into the SpacetimeDB `Entity` table.
+/// It spawns each requested entity from its kind's prefab and queues a command
+/// to insert the entity data into the SpacetimeDB `Entity` table.
 pub fn instantiate_entity_system(
     mut commands: Commands,
     mut events: EventReader<InstantiateEntityEvent>,
-    mut command_queue: ResMut<CommandQueue>,
+    mut entity_map: ResMut<StdbEntityMap>,
+    mut db_commands: StdbCommands,
+    prefabs: Res<PrefabRegistry>,
+    room: Res<CurrentRoom>,
 ) {
     for event in events.read() {
-        log::debug!("Processing InstantiateEntityEvent for {:?}", event.owner_identity);
+        if let Err(e) = spawn_entity_from_prefab(
+            &mut commands,
+            &mut entity_map,
+            &mut db_commands,
+            &prefabs,
+            room.0,
+            event,
+        ) {
+            log::error!("Failed to instantiate {:?} entity: {}", event.kind, e);
+        }
+    }
+}
 
-        // 1. Spawn the Bevy entity with its initial position.
-        let bevy_entity = commands
-            .spawn(Position(event.position))
-            // Consider adding the SpacetimeId component here if needed for lookups
-            // .insert(SpacetimeId(bevy_entity.index()))
-            .id();
+/// One-shot Bevy system spawning a single entity, run immediately by `enter_game`
+/// and `spawn_entity`. Returns the allocated SpacetimeDB entity ID.
+pub fn instantiate_entity_now(
+    In(request): In<InstantiateEntityEvent>,
+    mut commands: Commands,
+    mut entity_map: ResMut<StdbEntityMap>,
+    mut db_commands: StdbCommands,
+    prefabs: Res<PrefabRegistry>,
+    room: Res<CurrentRoom>,
+) -> Result<u32, String> {
+    spawn_entity_from_prefab(
+        &mut commands,
+        &mut entity_map,
+        &mut db_commands,
+        &prefabs,
+        room.0,
+        &request,
+    )
+}
+
+/// Spawns the Bevy entity for `request` from the prefab of its kind and queues the insert
+/// of its `Entity` row in `room`. Returns the allocated SpacetimeDB entity ID, or an error
+/// if no prefab is registered for the kind.
+fn spawn_entity_from_prefab(
+    commands: &mut Commands,
+    entity_map: &mut StdbEntityMap,
+    db_commands: &mut StdbCommands,
+    prefabs: &PrefabRegistry,
+    room: RoomId,
+    request: &InstantiateEntityEvent,
+) -> Result<u32, String> {
+    log::debug!(
+        "Processing InstantiateEntityEvent for {:?} owned by {:?}",
+        request.kind,
+        request.owner_identity
+    );
+    let prefab = prefabs.get(request.kind)?;
+    let mut row = (prefab.row)(request);
+    (prefab.validate)(&row)?;
 
-        // Use the Bevy entity's index as the primary key for the SpacetimeDB table.
-        // This provides a direct link between the Bevy entity and the STDB row.
-        let new_entity_id = bevy_entity.index();
-
-        // Clone data needed for the closure (moving `event` data into the closure).
-        let position_to_insert = event.position;
-        let owner_identity_to_insert = event.owner_identity;
-
-        // 2. Queue a command to insert the entity into SpacetimeDB.
-        // This closure will be executed later within the `process_tick` reducer context.
-        let cmd: DbCommandClosure = Box::new(move |ctx| {
-            log::info!(
-                "Executing STDB insert for Bevy entity {}, owner {:?}",
-                new_entity_id,
-                owner_identity_to_insert
+    // 1. Spawn the Bevy entity with the prefab's components.
+    //    `SpacetimeId` is attached by the integration layer once the insert commits.
+    let bevy_entity = (prefab.spawn)(commands, &row);
+
+    // Allocate the row's primary key. IDs are never reused, unlike Bevy entity indices.
+    let new_entity_id = entity_map.allocate(bevy_entity);
+    row.entity_id = new_entity_id;
+    row.room_id = room;
+
+    // 2. Queue a command to insert the entity into SpacetimeDB.
+    // This closure will be executed later against the backend draining the queue.
+    let cmd = DbCommand::insert(Entity::TABLE, new_entity_id, move |db| {
+        log::info!(
+            "Executing STDB insert for {:?} entity {}, owner {:?}",
+            row.kind,
+            new_entity_id,
+            row.owner_identity
+        );
+        db.insert(row)?;
+        log::info!("Inserted STDB entity row with ID: {}", new_entity_id);
+        // Report the row ID so the integration layer links it to the Bevy entity.
+        Ok(Some(new_entity_id))
+    });
+    db_commands.push_for(bevy_entity, cmd);
+    log::trace!("Queued STDB insert command for entity ID {}", new_entity_id);
+    Ok(new_entity_id)
+}
+
+/// Bevy system that despawns Bevy entities whose `entity` row insert did not take effect
+/// (failed, dropped or rolled back), so the Bevy world does not keep entities without a
+/// backing row. Failed inserts into other tables leave the entity alone.
+pub fn despawn_failed_inserts_system(
+    mut commands: Commands,
+    mut failures: EventReader<DbCommandFailed>,
+) {
+    for failure in failures.read() {
+        let entity_row_insert = failure.kind == DbCommandKind::Insert
+            && failure.target.is_some_and(|(table, _)| table == Entity::TABLE);
+        if !entity_row_insert {
+            continue;
+        }
+        if let Some(bevy_entity) = failure.entity {
+            log::warn!(
+                "Despawning Bevy entity {} after {:?} STDB insert: {}",
+                bevy_entity,
+                failure.cause,
+                failure.error
             );
-            ctx.db.entity().try_insert(crate::Entity {
-                entity_id: new_entity_id,
-                position: position_to_insert,
-                owner_identity: owner_identity_to_insert,
-            })?;
-            log::info!("Inserted STDB entity row with ID: {}", new_entity_id);
-            // Return Ok(Some(new_entity_id)) if the integration layer needs to know the ID.
-            Ok(None)
-        });
-        command_queue.0.push(DbCommand::ExecuteClosure(cmd));
-        log::trace!("Queued STDB insert command for entity ID {}", new_entity_id);
+            // The integration layer unlinks its row ID in `Last`, after mirroring saw the despawn.
+            commands.entity(bevy_entity).try_despawn();
+        }
+    }
+}
+
+/// Bevy system that processes `PlayerPresence` events: a disconnected player's entity
+/// stops moving and starts its `DisconnectGrace` timer, which is cleared again if the
+/// player reconnects.
+pub fn track_player_presence_system(
+    mut presence: EventReader<FromReducer<PlayerPresence>>,
+    owners: Res<OwnerIndex>,
+    mut players: Query<(&mut DisconnectGrace, &mut MoveIntent, &mut InputBuffer)>,
+) {
+    for FromReducer { event, .. } in presence.read() {
+        let Some(&bevy_entity) = owners.0.get(&event.identity()) else {
+            continue;
+        };
+        let Ok((mut grace, mut intent, mut buffer)) = players.get_mut(bevy_entity) else {
+            continue;
+        };
+        match event {
+            PlayerPresence::Disconnected { grace: duration, .. } => {
+                grace.0 = Some(Timer::new(*duration, TimerMode::Once));
+                // Don't keep walking in the last input direction until the entity expires.
+                *intent = MoveIntent::default();
+                buffer.0.clear();
+            }
+            PlayerPresence::Connected { identity } => {
+                log::info!("Player {:?} resumed entity {}.", identity, bevy_entity);
+                grace.0 = None;
+            }
+        }
+    }
+}
+
+/// Bevy system advancing `DisconnectGrace` timers. When one finishes, the entity is
+/// despawned and the delete of its `Entity` row is queued; its `InputAck` row is
+/// deleted by mirroring.
+pub fn expire_disconnected_players_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(bevy::prelude::Entity, &mut DisconnectGrace, &Owner)>,
+    entity_map: Res<StdbEntityMap>,
+    mut db_commands: StdbCommands,
+) {
+    for (bevy_entity, mut grace, owner) in query.iter_mut() {
+        let Some(timer) = grace.0.as_mut() else {
+            continue; // The player is connected.
+        };
+        if !timer.tick(time.delta()).finished() {
+            continue;
+        }
+        log::info!("Grace period of player {:?} expired; despawning {}.", owner.0, bevy_entity);
+        if let Some(id) = entity_map.id_of(bevy_entity) {
+            let cmd = DbCommand::delete(Entity::TABLE, id, move |db| {
+                db.delete::<Entity>(id)?;
+                Ok(None)
+            });
+            db_commands.push_for(bevy_entity, cmd);
+        }
+        commands.entity(bevy_entity).despawn();
     }
 }
 
 /// Bevy system that processes `PlayerInputEvent`s.
-/// It finds the corresponding Bevy entity and updates its local `Position` component.
+/// It finds the sender's Bevy entity and appends the input to its `InputBuffer`,
+/// dropping inputs whose sequence number is not newer than the latest known one.
 /// This system *only* modifies Bevy state.
-pub fn apply_player_movement_system(
-    mut events: EventReader<PlayerInputEvent>,
-    // Query for Bevy entities that have a Position component.
-    mut query: Query<(bevy::prelude::Entity, &mut Position)>,
+pub fn buffer_player_input_system(
+    mut presence: EventReader<FromReducer<PlayerPresence>>,
+    mut events: EventReader<FromReducer<PlayerInputEvent>>,
+    owners: Res<OwnerIndex>,
+    mut query: Query<(&mut InputBuffer, &mut LastProcessedInput)>,
 ) {
-    for event in events.read() {
-        log::trace!("Processing PlayerInputEvent for entity ID {}", event.player_id);
-        // Iterate through Bevy entities with Position components.
-        for (bevy_entity, mut position) in query.iter_mut() {
-            // Match the Bevy entity's index with the ID from the event.
-            if bevy_entity.index() == event.player_id {
-                log::trace!("Applying movement to Bevy entity {}", bevy_entity.index());
-                // Update the Bevy `Position` component directly.
-                position.0.x += event.direction.x;
-                position.0.y += event.direction.y;
-                log::trace!("Updated Bevy Position for {}: {:?}", bevy_entity.index(), position.0);
-
-                // Stop searching for this event, assuming one Bevy entity per player_id.
-                break;
-            }
+    for FromReducer { event, .. } in presence.read() {
+        // A returning player numbers its inputs from scratch; restart its sequence.
+        let PlayerPresence::Connected { identity } = event else {
+            continue;
+        };
+        let Some(&bevy_entity) = owners.0.get(identity) else {
+            continue;
+        };
+        if let Ok((mut buffer, mut last_processed)) = query.get_mut(bevy_entity) {
+            log::debug!("Restarting input sequence of {:?}.", identity);
+            buffer.0.clear();
+            last_processed.0 = 0;
         }
     }
+
+    for FromReducer { meta, event } in events.read() {
+        log::trace!("Processing PlayerInputEvent #{} from {:?}", event.seq, meta.sender);
+        // Resolve the sender's identity to its Bevy entity.
+        let Some(&bevy_entity) = owners.0.get(&meta.sender) else {
+            log::warn!("Received input from identity {:?} which has no associated Entity.", meta.sender);
+            continue;
+        };
+        let Ok((mut buffer, last_processed)) = query.get_mut(bevy_entity) else {
+            continue;
+        };
+        let newest = buffer.0.back().map_or(last_processed.0, |&(seq, _)| seq);
+        if event.seq <= newest {
+            log::trace!("Dropping stale input #{} from {:?}.", event.seq, meta.sender);
+            continue;
+        }
+        if buffer.0.len() == MAX_BUFFERED_INPUTS {
+            buffer.0.pop_front();
+        }
+        buffer.0.push_back((event.seq, event.direction));
+    }
 }
 
-/// Bevy system that detects changes in the `Position` component.
-/// When a change is detected, it queues a command to update the corresponding
-/// entity's position in the SpacetimeDB `Entity` table.
-pub fn update_stdb_position_system(
-    // Query for entities where the Position component has changed since the last update.
-    query: Query<(bevy::prelude::Entity, &Position), Changed<Position>>,
-    mut command_queue: ResMut<CommandQueue>,
+/// Bevy system run in `FixedUpdate` applying one buffered input per player and step: the
+/// oldest input becomes its `MoveIntent`, and its sequence number is acknowledged in
+/// `LastProcessedInput`. Inputs left over wait for the next steps.
+pub fn consume_input_buffer_system(
+    mut query: Query<(&mut InputBuffer, &mut MoveIntent, &mut LastProcessedInput)>,
 ) {
-    for (bevy_entity, position) in query.iter() {
-        // Get the ID (which matches the SpacetimeDB entity_id).
-        let entity_id_to_update = bevy_entity.index();
-        // Clone the current position value to move into the closure.
-        let new_position = position.0;
-
-        log::trace!(
-            "Detected position change for Bevy entity {}, queuing STDB update.",
-            entity_id_to_update
-        );
+    for (mut buffer, mut intent, mut last_processed) in query.iter_mut() {
+        let Some((seq, direction)) = buffer.0.pop_front() else {
+            continue; // No pending input; keep the current intent.
+        };
+        intent.0 = direction;
+        last_processed.0 = seq;
+    }
+}
 
-        // Queue a command to update the SpacetimeDB table.
-        let cmd: DbCommandClosure = Box::new(move |ctx| {
-            log::trace!(
-                "Executing STDB position update for entity {} to {:?}",
-                entity_id_to_update,
-                new_position
-            );
-            // Find the SpacetimeDB row by its primary key (entity_id).
-            // Call .iter() first to get an iterator, then filter.
-            if let Some(mut entity_row) = ctx
-                .db
-                .entity()
-                .iter() // Get iterator first
-                .filter(|e| e.entity_id == entity_id_to_update)
-                .next()
-            {
-                // Update the position field.
-                entity_row.position = new_position;
-                // Apply the update to the database table using the PK index.
-                ctx.db.entity().entity_id().update(entity_row);
-                log::trace!("Updated STDB entity {} position.", entity_id_to_update);
-            } else {
-                // This might happen if the entity was deleted between the Bevy update and STDB update.
-                log::warn!(
-                    "Could not find SpacetimeDB entity {} to update position.",
-                    entity_id_to_update
-                );
-            }
-            Ok(None)
-        });
-        command_queue.0.push(DbCommand::ExecuteClosure(cmd));
-        log::trace!("Queued STDB position update command for entity {}", entity_id_to_update);
+/// Bevy system run in `FixedUpdate` that moves entities along their `MoveIntent`.
+/// Displacement is scaled by the fixed delta time, so speed is independent of tick timing.
+pub fn apply_player_movement_system(
+    time: Res<Time>,
+    mut query: Query<(bevy::prelude::Entity, &MoveIntent, &mut Position)>,
+) {
+    let step = PLAYER_SPEED * time.delta_secs();
+    for (bevy_entity, intent, mut position) in query.iter_mut() {
+        if intent.0.x == 0.0 && intent.0.y == 0.0 {
+            continue; // Don't touch Position, so idle entities aren't mirrored.
+        }
+        // Update the Bevy `Position` component directly.
+        position.0.x += intent.0.x * step;
+        position.0.y += intent.0.y * step;
+        log::trace!("Updated Bevy Position for {}: {:?}", bevy_entity, position.0);
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy_spacetimedb_server::{process_bevy_commands_with, InMemoryDb, SpacetimeId};
+
+    const ROOM: RoomId = 3;
+
+    // Bevy App of a room with the prefabs, the spawning system and the `Position` mirror,
+    // without the host.
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(SpacetimeDbServerPlugin::default());
+        app.add_plugins(MirrorPlugin::<Position>::default());
+        app.add_event::<InstantiateEntityEvent>();
+        app.init_resource::<OwnerIndex>();
+        app.insert_resource(CurrentRoom(ROOM));
+        app.add_systems(Update, instantiate_entity_system);
+        app.add_prefab::<PlayerPrefab>().add_prefab::<NpcPrefab>();
+        app
+    }
+
+    fn entity_row(entity_id: u32, owner: u8, x: f32) -> Entity {
+        Entity {
+            entity_id,
+            position: Vec2 { x, y: 0.0 },
+            owner_identity: Some(Identity::from_byte_array([owner; 32])),
+            room_id: ROOM,
+            kind: EntityKind::Player,
+        }
+    }
+
+    fn player_request(owner_identity: Option<Identity>) -> InstantiateEntityEvent {
+        InstantiateEntityEvent {
+            kind: EntityKind::Player,
+            owner_identity,
+            position: Vec2 { x: 1.0, y: 2.0 },
+        }
+    }
+
+    #[test]
+    fn instantiate_entity_inserts_row_and_links_spacetime_id() {
+        let mut app = test_app();
+        app.world_mut().send_event(player_request(Some(Identity::ZERO)));
+        app.update();
+
+        let mut db = InMemoryDb::new();
+        let stats = process_bevy_commands_with(&mut app, &mut db).unwrap();
+        assert_eq!(stats.executed, 1);
+
+        let rows = db.rows::<Entity>();
+        assert_eq!(rows.len(), 1);
+        let id = rows[0].entity_id;
+        let row = db.row::<Entity>(id).unwrap();
+        assert_eq!(row.kind, EntityKind::Player);
+        assert_eq!(row.owner_identity, Some(Identity::ZERO));
+        assert_eq!(row.room_id, ROOM);
+        assert_eq!((row.position.x, row.position.y), (1.0, 2.0));
+
+        let bevy_entity = app.world().resource::<StdbEntityMap>().entity_of(id).unwrap();
+        assert_eq!(app.world().get::<SpacetimeId>(bevy_entity), Some(&SpacetimeId(id)));
+        let owner = app.world().get::<Owner>(bevy_entity).map(|owner| owner.0);
+        assert_eq!(owner, Some(Identity::ZERO));
+    }
+
+    #[test]
+    fn ownerless_players_are_not_instantiated() {
+        let mut app = test_app();
+        app.world_mut().send_event(player_request(None));
+        app.update();
+
+        let mut db = InMemoryDb::new();
+        let stats = process_bevy_commands_with(&mut app, &mut db).unwrap();
+        assert_eq!(stats.queued, 0);
+        assert_eq!(db.len(Entity::TABLE), 0);
+        let mut positions = app.world_mut().query::<&Position>();
+        assert_eq!(positions.iter(app.world()).count(), 0);
+    }
+
+    // App with a `Position` at the origin mapped to row 10, next to an unrelated row 11.
+    fn mirrored_position_app() -> (App, InMemoryDb, bevy::prelude::Entity) {
+        let mut app = test_app();
+        let mut db = InMemoryDb::new()
+            .with_row(entity_row(10, 1, 0.0))
+            .with_row(entity_row(11, 2, 5.0));
+        let bevy_entity = app.world_mut().spawn((Position(Vec2::default()), SpacetimeId(10))).id();
+        app.world_mut().resource_mut::<StdbEntityMap>().insert(bevy_entity, 10);
+        app.update();
+        process_bevy_commands_with(&mut app, &mut db).unwrap();
+        (app, db, bevy_entity)
+    }
+
+    fn move_to(app: &mut App, db: &mut InMemoryDb, bevy_entity: bevy::prelude::Entity, x: f32) {
+        app.world_mut().get_mut::<Position>(bevy_entity).unwrap().0.x = x;
+        app.update();
+        process_bevy_commands_with(app, db).unwrap();
+    }
+
+    #[test]
+    fn position_changes_are_quantized_and_mirrored_to_their_row_only() {
+        let (mut app, mut db, bevy_entity) = mirrored_position_app();
+        move_to(&mut app, &mut db, bevy_entity, 4.004);
+
+        assert!((db.row::<Entity>(10).unwrap().position.x - 4.0).abs() < 1e-5);
+        assert_eq!(db.row::<Entity>(11).unwrap().position.x, 5.0);
+        assert_eq!(db.len(Entity::TABLE), 2);
+    }
+
+    #[test]
+    fn position_changes_below_epsilon_are_written_once_settled() {
+        let (mut app, mut db, bevy_entity) = mirrored_position_app();
+        let x = 0.02; // Below `POSITION_EPSILON`, and a multiple of `POSITION_QUANTUM`.
+        move_to(&mut app, &mut db, bevy_entity, x);
+        assert_eq!(db.row::<Entity>(10).unwrap().position.x, 0.0);
+
+        // No change this tick: the held back position is written.
+        app.update();
+        process_bevy_commands_with(&mut app, &mut db).unwrap();
+        assert!((db.row::<Entity>(10).unwrap().position.x - x).abs() < 1e-5);
+    }
+
+    #[test]
+    fn one_buffered_input_is_applied_and_acknowledged_per_fixed_step() {
+        let mut app = App::new();
+        let left = Vec2 { x: -1.0, y: 0.0 };
+        let up = Vec2 { x: 0.0, y: 1.0 };
+        let player = app
+            .world_mut()
+            .spawn((
+                MoveIntent::default(),
+                InputBuffer([(4, left), (5, up)].into()),
+                LastProcessedInput(3),
+            ))
+            .id();
+
+        app.world_mut().run_system_once(consume_input_buffer_system).unwrap();
+        let world = app.world();
+        assert_eq!(world.get::<MoveIntent>(player).unwrap().0.x, -1.0);
+        assert_eq!(world.get::<LastProcessedInput>(player).unwrap().0, 4);
+        assert_eq!(world.get::<InputBuffer>(player).unwrap().0.len(), 1);
+
+        app.world_mut().run_system_once(consume_input_buffer_system).unwrap();
+        app.world_mut().run_system_once(consume_input_buffer_system).unwrap();
+        // An empty buffer keeps the latest intent and acknowledgement.
+        let world = app.world();
+        assert_eq!(world.get::<MoveIntent>(player).unwrap().0.y, 1.0);
+        assert_eq!(world.get::<LastProcessedInput>(player).unwrap().0, 5);
+    }
+
+    #[test]
+    fn sanitize_direction_clamps_and_rejects() {
+        let direction = sanitize_direction(0.3, -0.4).unwrap();
+        assert_eq!((direction.x, direction.y), (0.3, -0.4));
+
+        let direction = sanitize_direction(3.0, 4.0).unwrap();
+        assert!((direction.x.hypot(direction.y) - MAX_INPUT_MAGNITUDE).abs() < 1e-6);
+        assert!((direction.x / direction.y - 0.75).abs() < 1e-6);
+
+        assert!(sanitize_direction(f32::NAN, 0.0).is_err());
+        assert!(sanitize_direction(0.0, f32::INFINITY).is_err());
+    }
+
+    #[test]
+    fn validate_player_name_checks_length_and_characters() {
+        assert!(validate_player_name("Alice_01").is_ok());
+        assert!(validate_player_name("a-b").is_ok());
+        assert!(validate_player_name("ab").is_err());
+        assert!(validate_player_name(&"a".repeat(25)).is_err());
+        assert!(validate_player_name("Alice Smith").is_err());
+        assert!(validate_player_name("Ålice").is_err());
+        assert_eq!(player_name_key("Alice_01"), player_name_key("aLICE_01"));
+    }
+}
@@ -0,0 +1,198 @@
+// Standard Library Imports
+use std::collections::HashMap;
+
+// External Crate Imports
+use bevy::prelude::*;
+use spacetimedb::ReducerContext;
+
+// Workspace Crate Imports (Integration Library)
+use bevy_spacetimedb_server::{RoomId, StdbAppExt};
+
+// Crate Imports
+use crate::{
+    disconnect_grace, entity, input_ack, is_online, DisconnectGrace, EntityKind, InputBuffer,
+    InstantiateEntityEvent, LastProcessedInput, MoveIntent, Owner, Position,
+};
+
+// --- Prefab Trait ---
+
+/// Describes one `EntityKind`: the `Entity` row a spawn request becomes, and the Bevy
+/// bundle simulating it. Registered with `PrefabAppExt::add_prefab`.
+pub trait Prefab: 'static {
+    /// The kind of entity this prefab spawns.
+    const KIND: EntityKind;
+
+    /// Components spawned in the Bevy world for each entity of this kind.
+    type Bundle: Bundle;
+
+    /// Row template for a new entity requested by `request`.
+    /// `entity_id` and `room_id` are filled in by the spawner.
+    fn row(request: &InstantiateEntityEvent) -> crate::Entity {
+        crate::Entity {
+            entity_id: 0,
+            position: request.position,
+            owner_identity: request.owner_identity,
+            room_id: 0,
+            kind: Self::KIND,
+        }
+    }
+
+    /// Checks that `row` can be spawned by this prefab. Rows failing it are rejected
+    /// when requested and skipped when loaded. Defaults to accepting every row.
+    fn validate(_row: &crate::Entity) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Bevy components of a newly spawned entity with the given (validated) row.
+    fn bundle(row: &crate::Entity) -> Self::Bundle;
+
+    /// Bevy components of an entity rebuilt from its existing row after a module restart.
+    /// Defaults to `bundle`.
+    fn load(_ctx: &ReducerContext, row: &crate::Entity) -> Self::Bundle {
+        Self::bundle(row)
+    }
+}
+
+// --- Prefab Registry ---
+
+/// Type-erased functions of a registered `Prefab`.
+#[derive(Clone, Copy)]
+pub struct PrefabEntry {
+    /// Builds the row template of a spawn request.
+    pub row: fn(&InstantiateEntityEvent) -> crate::Entity,
+    /// Checks that a row can be spawned.
+    pub validate: fn(&crate::Entity) -> Result<(), String>,
+    /// Spawns the Bevy entity of a row.
+    pub spawn: fn(&mut Commands, &crate::Entity) -> bevy::prelude::Entity,
+}
+
+/// Bevy `Resource` mapping each registered `EntityKind` to its prefab.
+#[derive(Resource, Default)]
+pub struct PrefabRegistry(pub HashMap<EntityKind, PrefabEntry>);
+
+impl PrefabRegistry {
+    /// Returns the prefab of `kind`, failing if none is registered.
+    pub fn get(&self, kind: EntityKind) -> Result<&PrefabEntry, String> {
+        self.0.get(&kind).ok_or_else(|| format!("No prefab registered for {:?}", kind))
+    }
+}
+
+/// Extension trait registering prefabs on a Bevy `App`.
+pub trait PrefabAppExt {
+    /// Registers `P` for spawn requests of `P::KIND`, and rebuilds entities of that
+    /// kind from the `Entity` table after a module restart.
+    fn add_prefab<P: Prefab>(&mut self) -> &mut Self;
+}
+
+impl PrefabAppExt for App {
+    fn add_prefab<P: Prefab>(&mut self) -> &mut Self {
+        let entry = PrefabEntry {
+            row: P::row,
+            validate: P::validate,
+            spawn: |commands, row| commands.spawn(P::bundle(row)).id(),
+        };
+        self.init_resource::<PrefabRegistry>();
+        self.world_mut().resource_mut::<PrefabRegistry>().0.insert(P::KIND, entry);
+        self.add_row_loader("entity", load_prefab_rows::<P>)
+    }
+}
+
+/// Row loader turning each valid `Entity` row of kind `P::KIND` in `room` into a Bevy entity.
+fn load_prefab_rows<P: Prefab>(ctx: &ReducerContext, room: RoomId) -> Vec<(u32, P::Bundle)> {
+    ctx.db
+        .entity()
+        .room_id()
+        .filter(room)
+        .filter(|row| row.kind == P::KIND)
+        .filter(|row| match P::validate(row) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Skipping {:?} entity {}: {}", P::KIND, row.entity_id, e);
+                false
+            }
+        })
+        .map(|row| (row.entity_id, P::load(ctx, &row)))
+        .collect()
+}
+
+// --- Prefabs ---
+
+/// Player-controlled entity, spawned by `enter_room`.
+pub struct PlayerPrefab;
+
+impl Prefab for PlayerPrefab {
+    const KIND: EntityKind = EntityKind::Player;
+    type Bundle = (Position, MoveIntent, InputBuffer, LastProcessedInput, Owner, DisconnectGrace);
+
+    fn validate(row: &crate::Entity) -> Result<(), String> {
+        match row.owner_identity {
+            Some(_) => Ok(()),
+            None => Err("Player entities must have an owner".to_string()),
+        }
+    }
+
+    fn bundle(row: &crate::Entity) -> Self::Bundle {
+        let owner = row.owner_identity.expect("validated player rows have an owner");
+        (
+            Position(row.position),
+            MoveIntent::default(),
+            InputBuffer::default(),
+            LastProcessedInput::default(),
+            Owner(owner),
+            DisconnectGrace::default(),
+        )
+    }
+
+    fn load(ctx: &ReducerContext, row: &crate::Entity) -> Self::Bundle {
+        let (position, intent, buffer, _, owner, _) = Self::bundle(row);
+        let last_input = ctx
+            .db
+            .input_ack()
+            .entity_id()
+            .find(row.entity_id)
+            .map_or(0, |ack| ack.last_processed_input);
+        // The grace timer only lives in memory; restart it for players who are offline.
+        let grace = if is_online(ctx, owner.0) {
+            DisconnectGrace::default()
+        } else {
+            DisconnectGrace(Some(Timer::new(disconnect_grace(ctx), TimerMode::Once)))
+        };
+        (position, intent, buffer, LastProcessedInput(last_input), owner, grace)
+    }
+}
+
+/// Server-controlled character, moved by its `MoveIntent`.
+pub struct NpcPrefab;
+
+impl Prefab for NpcPrefab {
+    const KIND: EntityKind = EntityKind::Npc;
+    type Bundle = (Position, MoveIntent);
+
+    fn bundle(row: &crate::Entity) -> Self::Bundle {
+        (Position(row.position), MoveIntent::default())
+    }
+}
+
+/// Projectile, moved by its `MoveIntent`. Its owner is the shooter, if any.
+pub struct ProjectilePrefab;
+
+impl Prefab for ProjectilePrefab {
+    const KIND: EntityKind = EntityKind::Projectile;
+    type Bundle = (Position, MoveIntent);
+
+    fn bundle(row: &crate::Entity) -> Self::Bundle {
+        (Position(row.position), MoveIntent::default())
+    }
+}
+
+/// Static item lying in the world.
+pub struct PickupPrefab;
+
+impl Prefab for PickupPrefab {
+    const KIND: EntityKind = EntityKind::Pickup;
+    type Bundle = (Position,);
+
+    fn bundle(row: &crate::Entity) -> Self::Bundle {
+        (Position(row.position),)
+    }
+}